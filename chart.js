@@ -1,10 +1,66 @@
 let chartInstance = null;
+let chartDetails = [];
 
-window.createOrUpdateChart = function(canvasId, labels, buyData, rentData) {
+function formatDollars(value) {
+    return '$' + value.toLocaleString('en-US', {maximumFractionDigits: 0});
+}
+
+// Lines explaining how each side's net worth is made up for a given year
+function netWorthComposition(index) {
+    const d = chartDetails[index];
+    if (!d) return [];
+    return [
+        '',
+        'Buy: home ' + formatDollars(d.home_value) + ' - mortgage ' + formatDollars(d.mortgage_balance),
+        '  - selling costs ' + formatDollars(d.selling_costs) + ' - sale tax ' + formatDollars(d.home_sale_tax),
+        '  + portfolio ' + formatDollars(d.buyer_portfolio) + ' - tax ' + formatDollars(d.buyer_portfolio_tax),
+        'Rent: portfolio ' + formatDollars(d.renter_portfolio) + ' - tax ' + formatDollars(d.renter_portfolio_tax),
+        'Monthly: buy ' + formatDollars(d.buy_monthly_cost) + ' vs rent ' + formatDollars(d.current_rent),
+    ];
+}
+
+// Dashed line where buying pulls ahead for good (month since purchase, or null)
+let breakEvenMonth = null;
+
+const breakEvenPlugin = {
+    id: 'breakEven',
+    afterDatasetsDraw(chart) {
+        if (breakEvenMonth === null) return;
+        const x = chart.scales.x;
+        const area = chart.chartArea;
+
+        // Points sit at the end of each year: index 0 is month 12
+        const index = Math.max(breakEvenMonth / 12 - 1, 0);
+        const lower = Math.floor(index);
+        const upper = Math.min(Math.ceil(index), chart.data.labels.length - 1);
+        const lowerX = x.getPixelForValue(lower);
+        const px = lowerX + (x.getPixelForValue(upper) - lowerX) * (index - lower);
+
+        const ctx = chart.ctx;
+        ctx.save();
+        ctx.strokeStyle = '#059669';
+        ctx.lineWidth = 1.5;
+        ctx.setLineDash([6, 4]);
+        ctx.beginPath();
+        ctx.moveTo(px, area.top);
+        ctx.lineTo(px, area.bottom);
+        ctx.stroke();
+        ctx.setLineDash([]);
+        ctx.fillStyle = '#059669';
+        ctx.font = '12px sans-serif';
+        ctx.textAlign = px > area.right - 80 ? 'right' : 'left';
+        ctx.fillText('Break-even', px + (ctx.textAlign === 'right' ? -4 : 4), area.top + 12);
+        ctx.restore();
+    }
+};
+
+window.createOrUpdateChart = function(canvasId, labels, buyData, rentData, details, breakEven) {
     const canvas = document.getElementById(canvasId);
     if (!canvas) return;
 
     const ctx = canvas.getContext('2d');
+    chartDetails = details || [];
+    breakEvenMonth = breakEven === undefined ? null : breakEven;
 
     if (chartInstance) {
         chartInstance.data.labels = labels;
@@ -16,6 +72,7 @@ window.createOrUpdateChart = function(canvasId, labels, buyData, rentData) {
 
     chartInstance = new Chart(ctx, {
         type: 'line',
+        plugins: [breakEvenPlugin],
         data: {
             labels: labels,
             datasets: [
@@ -61,6 +118,9 @@ window.createOrUpdateChart = function(canvasId, labels, buyData, rentData) {
                         label: function(context) {
                             let value = context.parsed.y;
                             return context.dataset.label + ': $' + value.toLocaleString('en-US', {maximumFractionDigits: 0});
+                        },
+                        afterBody: function(items) {
+                            return items.length ? netWorthComposition(items[0].dataIndex) : [];
                         }
                     }
                 }
@@ -95,3 +155,105 @@ window.createOrUpdateChart = function(canvasId, labels, buyData, rentData) {
         }
     });
 };
+
+let fanChartInstance = null;
+
+// Percentile bands (p10-p90 and p25-p75) with a median line for each side
+function fanDatasets(label, bands, color, fillColor) {
+    const band = (key, fill) => ({
+        label: label + ' ' + key,
+        data: bands.map(b => b[key]),
+        borderColor: 'transparent',
+        backgroundColor: fillColor,
+        pointRadius: 0,
+        fill: fill,
+        tension: 0.3,
+    });
+    return [
+        band('p90', false),
+        band('p10', '-1'),
+        band('p75', false),
+        band('p25', '-1'),
+        {
+            label: label + ' (median)',
+            data: bands.map(b => b.p50),
+            borderColor: color,
+            backgroundColor: color,
+            pointRadius: 2,
+            fill: false,
+            tension: 0.3,
+        },
+    ];
+}
+
+window.createOrUpdateFanChart = function(canvasId, labels, buyBands, rentBands) {
+    const canvas = document.getElementById(canvasId);
+    if (!canvas) return;
+
+    const datasets = fanDatasets('Buy', buyBands, '#2563eb', 'rgba(37, 99, 235, 0.15)')
+        .concat(fanDatasets('Rent', rentBands, '#dc2626', 'rgba(220, 38, 38, 0.15)'));
+
+    // The canvas is recreated whenever the section is re-rendered
+    if (fanChartInstance && fanChartInstance.canvas === canvas) {
+        fanChartInstance.data.labels = labels;
+        fanChartInstance.data.datasets = datasets;
+        fanChartInstance.update('none');
+        return;
+    }
+    if (fanChartInstance) {
+        fanChartInstance.destroy();
+    }
+
+    fanChartInstance = new Chart(canvas.getContext('2d'), {
+        type: 'line',
+        data: { labels: labels, datasets: datasets },
+        options: {
+            responsive: true,
+            maintainAspectRatio: false,
+            interaction: {
+                intersect: false,
+                mode: 'index',
+            },
+            plugins: {
+                legend: {
+                    position: 'bottom',
+                    labels: {
+                        usePointStyle: true,
+                        padding: 20,
+                        filter: item => item.text.endsWith('(median)'),
+                    }
+                },
+                tooltip: {
+                    callbacks: {
+                        label: function(context) {
+                            return context.dataset.label + ': ' + formatDollars(context.parsed.y);
+                        }
+                    }
+                }
+            },
+            scales: {
+                x: {
+                    grid: {
+                        display: false
+                    }
+                },
+                y: {
+                    title: {
+                        display: true,
+                        text: 'Net Worth ($)'
+                    },
+                    ticks: {
+                        callback: function(value) {
+                            if (Math.abs(value) >= 1000000) {
+                                return '$' + (value / 1000000).toFixed(1) + 'M';
+                            } else if (Math.abs(value) >= 1000) {
+                                return '$' + (value / 1000).toFixed(0) + 'K';
+                            }
+                            return '$' + value;
+                        }
+                    }
+                }
+            }
+        }
+    });
+};
@@ -1,8 +1,20 @@
 use leptos::*;
 use wasm_bindgen::prelude::*;
-use crate::calculations::{self, Inputs, CalculationResult, generate_sensitivity_data};
-
-fn call_create_or_update_chart(canvas_id: &str, labels: &[String], buy_data: &[f64], rent_data: &[f64]) {
+use crate::calculations::{
+    self, AccountType, BreakEvenValue, BuyDown, CapexItem, ExitStrategy, FundingSource, ImprovementProject, Inputs, CalculationResult, Detail, MarketPath, ExtraPayment, FilingStatus, MortgageType, RefinanceEvent,
+    YearlySnapshot, generate_sensitivity_data, generate_sensitivity_grid, tornado_analysis,
+};
+use crate::backtest;
+use crate::monte_carlo::{self, MonteCarloResult, MonteCarloSettings, PercentileBand, RateDistribution};
+
+fn call_create_or_update_chart(
+    canvas_id: &str,
+    labels: &[String],
+    buy_data: &[f64],
+    rent_data: &[f64],
+    details: &[YearlySnapshot],
+    break_even_month: Option<u32>,
+) {
     let window = web_sys::window().unwrap();
     let func = js_sys::Reflect::get(&window, &JsValue::from_str("createOrUpdateChart"))
         .unwrap()
@@ -24,33 +36,170 @@ fn call_create_or_update_chart(canvas_id: &str, labels: &[String], buy_data: &[f
         rent_array.push(&JsValue::from_f64(val));
     }
 
-    let _ = func.call4(
+    // Per-year composition for the chart tooltips
+    let details_value = serde_wasm_bindgen::to_value(details).unwrap_or(JsValue::NULL);
+
+    let break_even_value = break_even_month.map_or(JsValue::NULL, |m| JsValue::from_f64(m as f64));
+
+    let _ = func.call6(
         &JsValue::NULL,
         &JsValue::from_str(canvas_id),
         &labels_array,
         &buy_array,
         &rent_array,
+        &details_value,
+        &break_even_value,
+    );
+}
+
+fn call_create_or_update_fan_chart(
+    canvas_id: &str,
+    labels: &[String],
+    buy_bands: &[PercentileBand],
+    rent_bands: &[PercentileBand],
+) {
+    let window = web_sys::window().unwrap();
+    let func = js_sys::Reflect::get(&window, &JsValue::from_str("createOrUpdateFanChart"))
+        .unwrap()
+        .dyn_into::<js_sys::Function>()
+        .unwrap();
+
+    let labels_array = js_sys::Array::new();
+    for label in labels {
+        labels_array.push(&JsValue::from_str(label));
+    }
+
+    let buy_value = serde_wasm_bindgen::to_value(buy_bands).unwrap_or(JsValue::NULL);
+    let rent_value = serde_wasm_bindgen::to_value(rent_bands).unwrap_or(JsValue::NULL);
+
+    let _ = func.call4(
+        &JsValue::NULL,
+        &JsValue::from_str(canvas_id),
+        &labels_array,
+        &buy_value,
+        &rent_value,
     );
 }
 
+/// A slider's field, value and current (editable) bounds
+#[derive(Clone, Copy)]
+struct SliderBounds {
+    field: &'static str,
+    label: &'static str,
+    value: ReadSignal<f64>,
+    min: ReadSignal<f64>,
+    max: ReadSignal<f64>,
+}
+
+/// Every mounted slider, so analysis views can offer the same fields and ranges
+#[derive(Clone, Copy)]
+struct SliderRegistry(RwSignal<Vec<SliderBounds>>);
+
+impl SliderRegistry {
+    fn register(&self, bounds: SliderBounds) {
+        self.0.update(|sliders| {
+            sliders.retain(|s| s.field != bounds.field);
+            sliders.push(bounds);
+        });
+    }
+
+    fn unregister(&self, field: &'static str) {
+        self.0.update(|sliders| sliders.retain(|s| s.field != field));
+    }
+
+    fn get(&self, field: &str) -> Option<SliderBounds> {
+        self.0.with(|sliders| sliders.iter().find(|s| s.field == field).copied())
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
+    provide_context(SliderRegistry(create_rw_signal(Vec::new())));
+
     // Create signals for all inputs
     let (home_price, set_home_price) = create_signal(400_000.0);
     let (down_payment_percent, set_down_payment_percent) = create_signal(20.0);
     let (mortgage_rate, set_mortgage_rate) = create_signal(6.5);
     let (loan_term_years, set_loan_term_years) = create_signal(30.0);
+    let (mortgage_type, set_mortgage_type) = create_signal(MortgageType::Fixed);
+    let (arm_index_rate, set_arm_index_rate) = create_signal(4.0);
+    let (arm_index_change, set_arm_index_change) = create_signal(0.0);
+    let (arm_margin, set_arm_margin) = create_signal(2.75);
+    let (arm_initial_cap, set_arm_initial_cap) = create_signal(2.0);
+    let (arm_periodic_cap, set_arm_periodic_cap) = create_signal(2.0);
+    let (arm_lifetime_cap, set_arm_lifetime_cap) = create_signal(5.0);
+    let (discount_points, set_discount_points) = create_signal(0.0);
+    let (rate_reduction_per_point, set_rate_reduction_per_point) = create_signal(0.25);
+    let (buy_down, set_buy_down) = create_signal(BuyDown::None);
+    let (buy_down_seller_paid, set_buy_down_seller_paid) = create_signal(true);
+    let (extra_monthly_principal, set_extra_monthly_principal) = create_signal(0.0);
+    let (extra_annual_principal, set_extra_annual_principal) = create_signal(0.0);
+    let (extra_payments, set_extra_payments) = create_signal(Vec::<ExtraPayment>::new());
+    let (refinances, set_refinances) = create_signal(Vec::<RefinanceEvent>::new());
+    let (improvements, set_improvements) = create_signal(Vec::<ImprovementProject>::new());
+    let (heloc_rate, set_heloc_rate) = create_signal(8.5);
+    let (heloc_term_years, set_heloc_term_years) = create_signal(10.0);
+    let (pmi_rate, set_pmi_rate) = create_signal(0.5);
+    let (pmi_request_cancellation, set_pmi_request_cancellation) = create_signal(false);
+    let (pmi_current_value_cancellation, set_pmi_current_value_cancellation) = create_signal(false);
     let (property_tax_rate, set_property_tax_rate) = create_signal(1.2);
+    let (assessment_capped, set_assessment_capped) = create_signal(false);
+    let (assessment_cap, set_assessment_cap) = create_signal(2.0);
+    let (homestead_exemption, set_homestead_exemption) = create_signal(0.0);
+    let (millage_rate, set_millage_rate) = create_signal(12.0);
     let (home_insurance, set_home_insurance) = create_signal(1_500.0);
+    let (home_insurance_increase, set_home_insurance_increase) = create_signal(3.0);
     let (hoa_monthly, set_hoa_monthly) = create_signal(0.0);
+    let (hoa_increase, set_hoa_increase) = create_signal(3.0);
     let (maintenance_percent, set_maintenance_percent) = create_signal(1.0);
+    let (maintenance_floor, set_maintenance_floor) = create_signal(0.0);
+    let (capex_items, set_capex_items) = create_signal(Vec::<CapexItem>::new());
+    let (capex_replaces_maintenance, set_capex_replaces_maintenance) = create_signal(false);
     let (home_appreciation, set_home_appreciation) = create_signal(3.0);
+    let (rental_income_monthly, set_rental_income_monthly) = create_signal(0.0);
+    let (rental_income_growth, set_rental_income_growth) = create_signal(3.0);
+    let (rental_vacancy_percent, set_rental_vacancy_percent) = create_signal(5.0);
+    let (rental_management_percent, set_rental_management_percent) = create_signal(0.0);
+    let (rental_income_taxed, set_rental_income_taxed) = create_signal(false);
+    let (rental_share_percent, set_rental_share_percent) = create_signal(25.0);
     let (closing_cost_percent, set_closing_cost_percent) = create_signal(3.0);
     let (selling_cost_percent, set_selling_cost_percent) = create_signal(6.0);
+    let (exit_strategy, set_exit_strategy) = create_signal(ExitStrategy::Sell);
+    let (move_out_year, set_move_out_year) = create_signal(5.0);
+    let (landlord_rent, set_landlord_rent) = create_signal(2_500.0);
+    let (landlord_vacancy_percent, set_landlord_vacancy_percent) = create_signal(8.0);
+    let (landlord_management_percent, set_landlord_management_percent) = create_signal(8.0);
+    let (landlord_insurance, set_landlord_insurance) = create_signal(1_800.0);
     let (monthly_rent, set_monthly_rent) = create_signal(2_000.0);
     let (rent_increase_rate, set_rent_increase_rate) = create_signal(3.0);
+    let (renewal_increase_cap, set_renewal_increase_cap) = create_signal(5.0);
     let (renters_insurance, set_renters_insurance) = create_signal(200.0);
-    let (investment_return, set_investment_return) = create_signal(7.0);
+    let (renters_insurance_increase, set_renters_insurance_increase) = create_signal(3.0);
+    let (security_deposit_months, set_security_deposit_months) = create_signal(1.0);
+    let (application_fee, set_application_fee) = create_signal(50.0);
+    let (broker_fee_percent, set_broker_fee_percent) = create_signal(0.0);
+    let (move_every_years, set_move_every_years) = create_signal(0.0);
+    let (moving_cost, set_moving_cost) = create_signal(2_000.0);
+    let (buyer_investment_return, set_buyer_investment_return) = create_signal(7.0);
+    let (buyer_expense_ratio, set_buyer_expense_ratio) = create_signal(0.05);
+    let (buyer_advisory_fee, set_buyer_advisory_fee) = create_signal(0.0);
+    let (buyer_equity_share, set_buyer_equity_share) = create_signal(100.0);
+    let (renter_investment_return, set_renter_investment_return) = create_signal(7.0);
+    let (renter_expense_ratio, set_renter_expense_ratio) = create_signal(0.05);
+    let (renter_advisory_fee, set_renter_advisory_fee) = create_signal(0.0);
+    let (renter_equity_share, set_renter_equity_share) = create_signal(100.0);
+    let (account_type, set_account_type) = create_signal(AccountType::Taxable);
+    let (dividend_yield, set_dividend_yield) = create_signal(1.5);
+    let (marginal_tax_rate, set_marginal_tax_rate) = create_signal(24.0);
+    let (filing_status, set_filing_status) = create_signal(FilingStatus::Single);
+    let (standard_deduction, set_standard_deduction) = create_signal(FilingStatus::Single.standard_deduction());
+    let (salt_cap, set_salt_cap) = create_signal(FilingStatus::Single.salt_cap());
+    let (state_income_tax, set_state_income_tax) = create_signal(0.0);
+    let (capital_gains_rate, set_capital_gains_rate) = create_signal(15.0);
+    let (home_sale_exclusion, set_home_sale_exclusion) = create_signal(FilingStatus::Single.home_sale_exclusion());
+    let (exclusion_min_years, set_exclusion_min_years) = create_signal(2.0);
+    let (inflation_rate, set_inflation_rate) = create_signal(2.5);
+    let (real_dollars, set_real_dollars) = create_signal(false);
     let (time_horizon_years, set_time_horizon_years) = create_signal(10.0);
 
     // Derived signal that creates Inputs struct
@@ -59,20 +208,104 @@ pub fn App() -> impl IntoView {
         down_payment_percent: down_payment_percent.get(),
         mortgage_rate: mortgage_rate.get(),
         loan_term_years: loan_term_years.get() as u32,
+        mortgage_type: mortgage_type.get(),
+        arm_index_rate: arm_index_rate.get(),
+        arm_index_change: arm_index_change.get(),
+        arm_margin: arm_margin.get(),
+        arm_initial_cap: arm_initial_cap.get(),
+        arm_periodic_cap: arm_periodic_cap.get(),
+        arm_lifetime_cap: arm_lifetime_cap.get(),
+        discount_points: discount_points.get(),
+        rate_reduction_per_point: rate_reduction_per_point.get(),
+        buy_down: buy_down.get(),
+        buy_down_seller_paid: buy_down_seller_paid.get(),
+        extra_monthly_principal: extra_monthly_principal.get(),
+        extra_annual_principal: extra_annual_principal.get(),
+        extra_payments: extra_payments.get(),
+        refinances: refinances.get(),
+        improvements: improvements.get(),
+        heloc_rate: heloc_rate.get(),
+        heloc_term_years: heloc_term_years.get() as u32,
+        pmi_rate: pmi_rate.get(),
+        pmi_request_cancellation: pmi_request_cancellation.get(),
+        pmi_current_value_cancellation: pmi_current_value_cancellation.get(),
         property_tax_rate: property_tax_rate.get(),
+        assessment_capped: assessment_capped.get(),
+        assessment_cap: assessment_cap.get(),
+        homestead_exemption: homestead_exemption.get(),
+        millage_rate: millage_rate.get(),
         home_insurance: home_insurance.get(),
+        home_insurance_increase: home_insurance_increase.get(),
         hoa_monthly: hoa_monthly.get(),
+        hoa_increase: hoa_increase.get(),
         maintenance_percent: maintenance_percent.get(),
+        maintenance_floor: maintenance_floor.get(),
+        capex_items: capex_items.get(),
+        capex_replaces_maintenance: capex_replaces_maintenance.get(),
         home_appreciation: home_appreciation.get(),
+        rental_income_monthly: rental_income_monthly.get(),
+        rental_income_growth: rental_income_growth.get(),
+        rental_vacancy_percent: rental_vacancy_percent.get(),
+        rental_management_percent: rental_management_percent.get(),
+        rental_income_taxed: rental_income_taxed.get(),
+        rental_share_percent: rental_share_percent.get(),
         closing_cost_percent: closing_cost_percent.get(),
         selling_cost_percent: selling_cost_percent.get(),
+        exit_strategy: exit_strategy.get(),
+        move_out_year: move_out_year.get() as u32,
+        landlord_rent: landlord_rent.get(),
+        landlord_vacancy_percent: landlord_vacancy_percent.get(),
+        landlord_management_percent: landlord_management_percent.get(),
+        landlord_insurance: landlord_insurance.get(),
         monthly_rent: monthly_rent.get(),
         rent_increase_rate: rent_increase_rate.get(),
+        renewal_increase_cap: renewal_increase_cap.get(),
         renters_insurance: renters_insurance.get(),
-        investment_return: investment_return.get(),
+        renters_insurance_increase: renters_insurance_increase.get(),
+        security_deposit_months: security_deposit_months.get(),
+        application_fee: application_fee.get(),
+        broker_fee_percent: broker_fee_percent.get(),
+        move_every_years: move_every_years.get() as u32,
+        moving_cost: moving_cost.get(),
+        buyer_investment_return: buyer_investment_return.get(),
+        buyer_expense_ratio: buyer_expense_ratio.get(),
+        buyer_advisory_fee: buyer_advisory_fee.get(),
+        buyer_equity_share: buyer_equity_share.get(),
+        renter_investment_return: renter_investment_return.get(),
+        renter_expense_ratio: renter_expense_ratio.get(),
+        renter_advisory_fee: renter_advisory_fee.get(),
+        renter_equity_share: renter_equity_share.get(),
+        account_type: account_type.get(),
+        dividend_yield: dividend_yield.get(),
+        marginal_tax_rate: marginal_tax_rate.get(),
+        filing_status: filing_status.get(),
+        standard_deduction: standard_deduction.get(),
+        salt_cap: salt_cap.get(),
+        state_income_tax: state_income_tax.get(),
+        capital_gains_rate: capital_gains_rate.get(),
+        home_sale_exclusion: home_sale_exclusion.get(),
+        exclusion_min_years: exclusion_min_years.get() as u32,
+        inflation_rate: inflation_rate.get(),
+        real_dollars: real_dollars.get(),
         time_horizon_years: time_horizon_years.get() as u32,
     });
 
+    // Switching filing status resets the deduction limits to that status's defaults
+    create_effect(move |_| {
+        let status = filing_status.get();
+        set_standard_deduction.set(status.standard_deduction());
+        set_salt_cap.set(status.salt_cap());
+        set_home_sale_exclusion.set(status.home_sale_exclusion());
+    });
+
+    // Switching ARM product resets the caps to that product's usual terms
+    create_effect(move |_| {
+        let (initial, periodic, lifetime) = mortgage_type.get().default_caps();
+        set_arm_initial_cap.set(initial);
+        set_arm_periodic_cap.set(periodic);
+        set_arm_lifetime_cap.set(lifetime);
+    });
+
     // Calculate results
     let result = create_memo(move |_| calculations::calculate(&inputs.get()));
 
@@ -81,7 +314,7 @@ pub fn App() -> impl IntoView {
             <h1>"Buy vs Rent Calculator"</h1>
             <p class="subtitle">"Compare the true cost of buying a home versus renting"</p>
 
-            <ResultBanner result=result />
+            <ResultBanner result=result real_dollars=real_dollars inputs=inputs />
 
             <div class="inputs-section">
                 <div class="section-title">"Time Horizon"</div>
@@ -96,6 +329,24 @@ pub fn App() -> impl IntoView {
                     field="time_horizon_years"
                     inputs=inputs
                 />
+
+                <SliderInput
+                    label="General Inflation Rate"
+                    value=inflation_rate
+                    set_value=set_inflation_rate
+                    min=0.0
+                    max=8.0
+                    step=0.25
+                    format_value=|v| format!("{:.2}%/year", v)
+                    field="inflation_rate"
+                    inputs=inputs
+                />
+
+                <ToggleInput
+                    label="Show results in today's dollars"
+                    value=real_dollars
+                    set_value=set_real_dollars
+                />
             </div>
 
             <div class="inputs-section">
@@ -164,23 +415,262 @@ pub fn App() -> impl IntoView {
                         inputs=inputs
                     />
                 </div>
+
+                <SelectInput
+                    label="Mortgage Type"
+                    options=MortgageType::ALL.iter().map(|m| (*m, m.label())).collect()
+                    value=mortgage_type
+                    set_value=set_mortgage_type
+                />
+
+                <Show when=move || mortgage_type.get().is_arm()>
+                    <div class="input-row">
+                        <SliderInput
+                            label="Index Rate Today"
+                            value=arm_index_rate
+                            set_value=set_arm_index_rate
+                            min=0.0
+                            max=10.0
+                            step=0.125
+                            format_value=|v| format!("{:.2}%", v)
+                            field="arm_index_rate"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Index Change"
+                            value=arm_index_change
+                            set_value=set_arm_index_change
+                            min=-1.0
+                            max=1.0
+                            step=0.05
+                            format_value=|v| format!("{:+.2}%/year", v)
+                            field="arm_index_change"
+                            inputs=inputs
+                        />
+                    </div>
+
+                    <div class="input-row">
+                        <SliderInput
+                            label="ARM Margin"
+                            value=arm_margin
+                            set_value=set_arm_margin
+                            min=0.0
+                            max=5.0
+                            step=0.125
+                            format_value=|v| format!("{:.2}%", v)
+                            field="arm_margin"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Initial Adjustment Cap"
+                            value=arm_initial_cap
+                            set_value=set_arm_initial_cap
+                            min=0.0
+                            max=6.0
+                            step=0.5
+                            format_value=|v| format!("{:.1}%", v)
+                            field="arm_initial_cap"
+                            inputs=inputs
+                        />
+                    </div>
+
+                    <div class="input-row">
+                        <SliderInput
+                            label="Periodic Adjustment Cap"
+                            value=arm_periodic_cap
+                            set_value=set_arm_periodic_cap
+                            min=0.0
+                            max=5.0
+                            step=0.5
+                            format_value=|v| format!("{:.1}%", v)
+                            field="arm_periodic_cap"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Lifetime Cap"
+                            value=arm_lifetime_cap
+                            set_value=set_arm_lifetime_cap
+                            min=0.0
+                            max=10.0
+                            step=0.5
+                            format_value=|v| format!("{:.1}%", v)
+                            field="arm_lifetime_cap"
+                            inputs=inputs
+                        />
+                    </div>
+                </Show>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Discount Points"
+                        value=discount_points
+                        set_value=set_discount_points
+                        min=0.0
+                        max=4.0
+                        step=0.25
+                        format_value=|v| format!("{:.2} pts", v)
+                        field="discount_points"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Rate Reduction per Point"
+                        value=rate_reduction_per_point
+                        set_value=set_rate_reduction_per_point
+                        min=0.0
+                        max=0.5
+                        step=0.05
+                        format_value=|v| format!("{:.2}%", v)
+                        field="rate_reduction_per_point"
+                        inputs=inputs
+                    />
+                </div>
+
+                <SelectInput
+                    label="Temporary Buy-Down"
+                    options=BuyDown::ALL.iter().map(|b| (*b, b.label())).collect()
+                    value=buy_down
+                    set_value=set_buy_down
+                />
+
+                <Show when=move || buy_down.get() != BuyDown::None>
+                    <ToggleInput
+                        label="Seller pays for the buy-down"
+                        value=buy_down_seller_paid
+                        set_value=set_buy_down_seller_paid
+                    />
+                </Show>
+
+                <SliderInput
+                    label="PMI Rate (while down payment < 20%)"
+                    value=pmi_rate
+                    set_value=set_pmi_rate
+                    min=0.0
+                    max=2.0
+                    step=0.05
+                    format_value=|v| format!("{:.2}% of loan/year", v)
+                    field="pmi_rate"
+                    inputs=inputs
+                />
+
+                <div class="input-row">
+                    <ToggleInput
+                        label="Request PMI removal at 80% of original price"
+                        value=pmi_request_cancellation
+                        set_value=set_pmi_request_cancellation
+                    />
+
+                    <ToggleInput
+                        label="Request PMI removal based on current value"
+                        value=pmi_current_value_cancellation
+                        set_value=set_pmi_current_value_cancellation
+                    />
+                </div>
             </div>
 
             <div class="inputs-section">
-                <div class="section-title">"Ongoing Home Costs"</div>
+                <div class="section-title">"Extra Principal Payments"</div>
 
                 <div class="input-row">
                     <SliderInput
-                        label="Property Tax Rate"
-                        value=property_tax_rate
-                        set_value=set_property_tax_rate
+                        label="Extra Each Month"
+                        value=extra_monthly_principal
+                        set_value=set_extra_monthly_principal
                         min=0.0
-                        max=4.0
-                        step=0.1
-                        format_value=|v| format!("{:.1}%/year", v)
-                        field="property_tax_rate"
+                        max=2_000.0
+                        step=50.0
+                        format_value=|v| format!("{}/month", format_currency(v))
+                        field="extra_monthly_principal"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Extra Each Year"
+                        value=extra_annual_principal
+                        set_value=set_extra_annual_principal
+                        min=0.0
+                        max=20_000.0
+                        step=500.0
+                        format_value=|v| format!("{}/year", format_currency(v))
+                        field="extra_annual_principal"
                         inputs=inputs
                     />
+                </div>
+
+                <ExtraPaymentsEditor payments=extra_payments set_payments=set_extra_payments />
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Refinancing"</div>
+                <RefinanceEditor refinances=refinances set_refinances=set_refinances />
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Ongoing Home Costs"</div>
+
+                <ToggleInput
+                    label="Tax a capped assessed value (Prop 13, Save Our Homes)"
+                    value=assessment_capped
+                    set_value=set_assessment_capped
+                />
+
+                <Show when=move || assessment_capped.get()>
+                    <div class="input-row">
+                        <SliderInput
+                            label="Millage Rate"
+                            value=millage_rate
+                            set_value=set_millage_rate
+                            min=0.0
+                            max=40.0
+                            step=0.5
+                            format_value=|v| format!("{:.1} mills", v)
+                            field="millage_rate"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Assessment Cap"
+                            value=assessment_cap
+                            set_value=set_assessment_cap
+                            min=0.0
+                            max=10.0
+                            step=0.5
+                            format_value=|v| format!("{:.1}%/year", v)
+                            field="assessment_cap"
+                            inputs=inputs
+                        />
+                    </div>
+                </Show>
+
+                <SliderInput
+                    label="Homestead Exemption"
+                    value=homestead_exemption
+                    set_value=set_homestead_exemption
+                    min=0.0
+                    max=100_000.0
+                    step=5_000.0
+                    format_value=|v| format!("{} off taxable value", format_currency(v))
+                    field="homestead_exemption"
+                    inputs=inputs
+                />
+
+                <div class="input-row">
+                    <Show when=move || !assessment_capped.get()>
+                        <SliderInput
+                            label="Property Tax Rate"
+                            value=property_tax_rate
+                            set_value=set_property_tax_rate
+                            min=0.0
+                            max=4.0
+                            step=0.1
+                            format_value=|v| format!("{:.1}%/year", v)
+                            field="property_tax_rate"
+                            inputs=inputs
+                        />
+                    </Show>
 
                     <SliderInput
                         label="Home Insurance"
@@ -220,6 +710,168 @@ pub fn App() -> impl IntoView {
                         inputs=inputs
                     />
                 </div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Insurance Increase"
+                        value=home_insurance_increase
+                        set_value=set_home_insurance_increase
+                        min=0.0
+                        max=10.0
+                        step=0.5
+                        format_value=|v| format!("{:.1}%/year", v)
+                        field="home_insurance_increase"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="HOA Increase"
+                        value=hoa_increase
+                        set_value=set_hoa_increase
+                        min=0.0
+                        max=10.0
+                        step=0.5
+                        format_value=|v| format!("{:.1}%/year", v)
+                        field="hoa_increase"
+                        inputs=inputs
+                    />
+                </div>
+
+                <SliderInput
+                    label="Maintenance Floor"
+                    value=maintenance_floor
+                    set_value=set_maintenance_floor
+                    min=0.0
+                    max=15_000.0
+                    step=250.0
+                    format_value=|v| if v == 0.0 { "None".to_string() } else { format!("{}/year today", format_currency(v)) }
+                    field="maintenance_floor"
+                    inputs=inputs
+                />
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Renovations"</div>
+
+                <ImprovementsEditor projects=improvements set_projects=set_improvements />
+
+                <Show when=move || improvements.get().iter().any(|p| p.funding == FundingSource::Heloc)>
+                    <div class="input-row">
+                        <SliderInput
+                            label="HELOC Rate"
+                            value=heloc_rate
+                            set_value=set_heloc_rate
+                            min=3.0
+                            max=15.0
+                            step=0.25
+                            format_value=|v| format!("{:.2}%", v)
+                            field="heloc_rate"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="HELOC Repayment"
+                            value=heloc_term_years
+                            set_value=set_heloc_term_years
+                            min=1.0
+                            max=30.0
+                            step=1.0
+                            format_value=|v| format!("{} years", v as u32)
+                            field="heloc_term_years"
+                            inputs=inputs
+                        />
+                    </div>
+                </Show>
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Major Repairs"</div>
+
+                <CapexEditor items=capex_items set_items=set_capex_items />
+
+                <Show when=move || !capex_items.get().is_empty()>
+                    <ToggleInput
+                        label="Replace the percentage maintenance model (keeps the floor)"
+                        value=capex_replaces_maintenance
+                        set_value=set_capex_replaces_maintenance
+                    />
+                </Show>
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Rental Income"</div>
+
+                <SliderInput
+                    label="Rent From a Room, Unit or ADU"
+                    value=rental_income_monthly
+                    set_value=set_rental_income_monthly
+                    min=0.0
+                    max=5_000.0
+                    step=50.0
+                    format_value=|v| format!("{}/month", format_currency(v))
+                    field="rental_income_monthly"
+                    inputs=inputs
+                />
+
+                <Show when=move || { rental_income_monthly.get() > 0.0 }>
+                    <div class="input-row">
+                        <SliderInput
+                            label="Rent Growth"
+                            value=rental_income_growth
+                            set_value=set_rental_income_growth
+                            min=0.0
+                            max=10.0
+                            step=0.5
+                            format_value=|v| format!("{:.1}%/year", v)
+                            field="rental_income_growth"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Vacancy"
+                            value=rental_vacancy_percent
+                            set_value=set_rental_vacancy_percent
+                            min=0.0
+                            max=25.0
+                            step=1.0
+                            format_value=|v| format!("{:.0}% of the year", v)
+                            field="rental_vacancy_percent"
+                            inputs=inputs
+                        />
+                    </div>
+
+                    <SliderInput
+                        label="Property Management"
+                        value=rental_management_percent
+                        set_value=set_rental_management_percent
+                        min=0.0
+                        max=15.0
+                        step=1.0
+                        format_value=|v| format!("{:.0}% of rent collected", v)
+                        field="rental_management_percent"
+                        inputs=inputs
+                    />
+
+                    <ToggleInput
+                        label="Report rental income for tax (with depreciation)"
+                        value=rental_income_taxed
+                        set_value=set_rental_income_taxed
+                    />
+
+                    <Show when=move || rental_income_taxed.get()>
+                        <SliderInput
+                            label="Share of Home Rented Out"
+                            value=rental_share_percent
+                            set_value=set_rental_share_percent
+                            min=5.0
+                            max=100.0
+                            step=5.0
+                            format_value=|v| format!("{:.0}% of the property", v)
+                            field="rental_share_percent"
+                            inputs=inputs
+                        />
+                    </Show>
+                </Show>
             </div>
 
             <div class="inputs-section">
@@ -252,6 +904,83 @@ pub fn App() -> impl IntoView {
                 </div>
             </div>
 
+            <div class="inputs-section">
+                <div class="section-title">"Exit Strategy"</div>
+
+                <SelectInput
+                    label="At the End"
+                    options=ExitStrategy::ALL.iter().map(|e| (*e, e.label())).collect()
+                    value=exit_strategy
+                    set_value=set_exit_strategy
+                />
+
+                <Show when=move || exit_strategy.get().keeps_as_rental()>
+                    <div class="input-row">
+                        <SliderInput
+                            label="Move Out After"
+                            value=move_out_year
+                            set_value=set_move_out_year
+                            min=1.0
+                            max=30.0
+                            step=1.0
+                            format_value=|v| format!("{} years", v as u32)
+                            field="move_out_year"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Market Rent for the Home"
+                            value=landlord_rent
+                            set_value=set_landlord_rent
+                            min=500.0
+                            max=10_000.0
+                            step=100.0
+                            format_value=|v| format!("{}/month today", format_currency(v))
+                            field="landlord_rent"
+                            inputs=inputs
+                        />
+                    </div>
+
+                    <div class="input-row">
+                        <SliderInput
+                            label="Vacancy"
+                            value=landlord_vacancy_percent
+                            set_value=set_landlord_vacancy_percent
+                            min=0.0
+                            max=25.0
+                            step=1.0
+                            format_value=|v| format!("{:.0}% of the year", v)
+                            field="landlord_vacancy_percent"
+                            inputs=inputs
+                        />
+
+                        <SliderInput
+                            label="Property Management"
+                            value=landlord_management_percent
+                            set_value=set_landlord_management_percent
+                            min=0.0
+                            max=15.0
+                            step=1.0
+                            format_value=|v| format!("{:.0}% of rent collected", v)
+                            field="landlord_management_percent"
+                            inputs=inputs
+                        />
+                    </div>
+
+                    <SliderInput
+                        label="Landlord Insurance"
+                        value=landlord_insurance
+                        set_value=set_landlord_insurance
+                        min=500.0
+                        max=6_000.0
+                        step=100.0
+                        format_value=|v| format!("{}/year", format_currency(v))
+                        field="landlord_insurance"
+                        inputs=inputs
+                    />
+                </Show>
+            </div>
+
             <div class="inputs-section">
                 <div class="section-title">"Rental Details"</div>
 
@@ -269,7 +998,7 @@ pub fn App() -> impl IntoView {
 
                 <div class="input-row">
                     <SliderInput
-                        label="Annual Rent Increase"
+                        label="Market Rent Increase"
                         value=rent_increase_rate
                         set_value=set_rent_increase_rate
                         min=0.0
@@ -280,6 +1009,20 @@ pub fn App() -> impl IntoView {
                         inputs=inputs
                     />
 
+                    <SliderInput
+                        label="Renewal Increase Cap"
+                        value=renewal_increase_cap
+                        set_value=set_renewal_increase_cap
+                        min=0.0
+                        max=10.0
+                        step=0.5
+                        format_value=|v| format!("{:.1}%/year", v)
+                        field="renewal_increase_cap"
+                        inputs=inputs
+                    />
+                </div>
+
+                <div class="input-row">
                     <SliderInput
                         label="Renter's Insurance"
                         value=renters_insurance
@@ -291,54 +1034,352 @@ pub fn App() -> impl IntoView {
                         field="renters_insurance"
                         inputs=inputs
                     />
-                </div>
-            </div>
 
-            <div class="inputs-section">
-                <div class="section-title">"Investment Assumptions"</div>
+                    <SliderInput
+                        label="Security Deposit"
+                        value=security_deposit_months
+                        set_value=set_security_deposit_months
+                        min=0.0
+                        max=3.0
+                        step=0.5
+                        format_value=|v| format!("{:.1} months' rent", v)
+                        field="security_deposit_months"
+                        inputs=inputs
+                    />
+                </div>
 
                 <SliderInput
-                    label="Investment Return Rate"
-                    value=investment_return
-                    set_value=set_investment_return
+                    label="Renter's Insurance Increase"
+                    value=renters_insurance_increase
+                    set_value=set_renters_insurance_increase
                     min=0.0
-                    max=15.0
+                    max=10.0
                     step=0.5
                     format_value=|v| format!("{:.1}%/year", v)
-                    field="investment_return"
+                    field="renters_insurance_increase"
                     inputs=inputs
                 />
-            </div>
 
-            <NetWorthChart result=result time_horizon=time_horizon_years />
+                <div class="input-row">
+                    <SliderInput
+                        label="Application Fee"
+                        value=application_fee
+                        set_value=set_application_fee
+                        min=0.0
+                        max=500.0
+                        step=25.0
+                        format_value=|v| format!("{}/lease", format_currency(v))
+                        field="application_fee"
+                        inputs=inputs
+                    />
 
-            <BreakdownSection result=result />
-        </div>
-    }
-}
+                    <SliderInput
+                        label="Broker Fee"
+                        value=broker_fee_percent
+                        set_value=set_broker_fee_percent
+                        min=0.0
+                        max=15.0
+                        step=1.0
+                        format_value=|v| format!("{:.0}% of annual rent", v)
+                        field="broker_fee_percent"
+                        inputs=inputs
+                    />
+                </div>
 
-fn format_currency(value: f64) -> String {
-    let abs_value = value.abs();
-    let sign = if value < 0.0 { "-" } else { "" };
-    if abs_value >= 1_000_000.0 {
-        format!("{}${:.2}M", sign, abs_value / 1_000_000.0)
-    } else if abs_value >= 1_000.0 {
-        format!("{}${:.0}K", sign, abs_value / 1_000.0)
-    } else {
-        format!("{}${:.0}", sign, abs_value)
-    }
-}
+                <div class="input-row">
+                    <SliderInput
+                        label="Move Every"
+                        value=move_every_years
+                        set_value=set_move_every_years
+                        min=0.0
+                        max=10.0
+                        step=1.0
+                        format_value=|v| if v == 0.0 { "Never".to_string() } else { format!("{} years", v as u32) }
+                        field="move_every_years"
+                        inputs=inputs
+                    />
 
-/// More precise currency format for sensitivity labels
-fn format_currency_precise(value: f64) -> String {
-    let abs_value = value.abs().round() as i64;
-    let sign = if value < 0.0 { "-" } else { "" };
-    if abs_value >= 1_000_000 {
-        let millions = value.abs() / 1_000_000.0;
-        format!("{}${:.2}M", sign, millions)
-    } else {
-        let formatted = abs_value
-            .to_string()
+                    <SliderInput
+                        label="Moving Cost"
+                        value=moving_cost
+                        set_value=set_moving_cost
+                        min=0.0
+                        max=10_000.0
+                        step=250.0
+                        format_value=|v| format!("{}/move", format_currency(v))
+                        field="moving_cost"
+                        inputs=inputs
+                    />
+                </div>
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Investment Assumptions"</div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Buyer's Investment Return"
+                        value=buyer_investment_return
+                        set_value=set_buyer_investment_return
+                        min=0.0
+                        max=15.0
+                        step=0.5
+                        format_value=|v| format!("{:.1}%/year", v)
+                        field="buyer_investment_return"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Renter's Investment Return"
+                        value=renter_investment_return
+                        set_value=set_renter_investment_return
+                        min=0.0
+                        max=15.0
+                        step=0.5
+                        format_value=|v| format!("{:.1}%/year", v)
+                        field="renter_investment_return"
+                        inputs=inputs
+                    />
+                </div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Buyer's Expense Ratio"
+                        value=buyer_expense_ratio
+                        set_value=set_buyer_expense_ratio
+                        min=0.0
+                        max=1.5
+                        step=0.05
+                        format_value=|v| format!("{:.2}%/year", v)
+                        field="buyer_expense_ratio"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Renter's Expense Ratio"
+                        value=renter_expense_ratio
+                        set_value=set_renter_expense_ratio
+                        min=0.0
+                        max=1.5
+                        step=0.05
+                        format_value=|v| format!("{:.2}%/year", v)
+                        field="renter_expense_ratio"
+                        inputs=inputs
+                    />
+                </div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Buyer's Advisory Fee"
+                        value=buyer_advisory_fee
+                        set_value=set_buyer_advisory_fee
+                        min=0.0
+                        max=2.0
+                        step=0.25
+                        format_value=|v| format!("{:.2}%/year", v)
+                        field="buyer_advisory_fee"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Renter's Advisory Fee"
+                        value=renter_advisory_fee
+                        set_value=set_renter_advisory_fee
+                        min=0.0
+                        max=2.0
+                        step=0.25
+                        format_value=|v| format!("{:.2}%/year", v)
+                        field="renter_advisory_fee"
+                        inputs=inputs
+                    />
+                </div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Buyer's Stock Allocation"
+                        value=buyer_equity_share
+                        set_value=set_buyer_equity_share
+                        min=0.0
+                        max=100.0
+                        step=5.0
+                        format_value=|v| format!("{:.0}% stocks", v)
+                        field="buyer_equity_share"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Renter's Stock Allocation"
+                        value=renter_equity_share
+                        set_value=set_renter_equity_share
+                        min=0.0
+                        max=100.0
+                        step=5.0
+                        format_value=|v| format!("{:.0}% stocks", v)
+                        field="renter_equity_share"
+                        inputs=inputs
+                    />
+                </div>
+
+                <div class="input-row">
+                    <SelectInput
+                        label="Account Type"
+                        options=AccountType::ALL.iter().map(|a| (*a, a.label())).collect()
+                        value=account_type
+                        set_value=set_account_type
+                    />
+
+                    <SliderInput
+                        label="Dividend Yield"
+                        value=dividend_yield
+                        set_value=set_dividend_yield
+                        min=0.0
+                        max=6.0
+                        step=0.25
+                        format_value=|v| format!("{:.2}%/year", v)
+                        field="dividend_yield"
+                        inputs=inputs
+                    />
+                </div>
+            </div>
+
+            <div class="inputs-section">
+                <div class="section-title">"Income Taxes"</div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Marginal Tax Rate"
+                        value=marginal_tax_rate
+                        set_value=set_marginal_tax_rate
+                        min=0.0
+                        max=50.0
+                        step=1.0
+                        format_value=|v| format!("{}%", v as u32)
+                        field="marginal_tax_rate"
+                        inputs=inputs
+                    />
+
+                    <SelectInput
+                        label="Filing Status"
+                        options=FilingStatus::ALL.iter().map(|s| (*s, s.label())).collect()
+                        value=filing_status
+                        set_value=set_filing_status
+                    />
+                </div>
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Standard Deduction"
+                        value=standard_deduction
+                        set_value=set_standard_deduction
+                        min=0.0
+                        max=40_000.0
+                        step=100.0
+                        format_value=format_currency
+                        field="standard_deduction"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="SALT Deduction Cap"
+                        value=salt_cap
+                        set_value=set_salt_cap
+                        min=0.0
+                        max=50_000.0
+                        step=100.0
+                        format_value=format_currency
+                        field="salt_cap"
+                        inputs=inputs
+                    />
+                </div>
+
+                <SliderInput
+                    label="State & Local Income Tax"
+                    value=state_income_tax
+                    set_value=set_state_income_tax
+                    min=0.0
+                    max=50_000.0
+                    step=500.0
+                    format_value=|v| format!("{}/year", format_currency(v))
+                    field="state_income_tax"
+                    inputs=inputs
+                />
+
+                <SliderInput
+                    label="Capital Gains Tax Rate"
+                    value=capital_gains_rate
+                    set_value=set_capital_gains_rate
+                    min=0.0
+                    max=40.0
+                    step=1.0
+                    format_value=|v| format!("{}%", v as u32)
+                    field="capital_gains_rate"
+                    inputs=inputs
+                />
+
+                <div class="input-row">
+                    <SliderInput
+                        label="Home Sale Gain Exclusion"
+                        value=home_sale_exclusion
+                        set_value=set_home_sale_exclusion
+                        min=0.0
+                        max=500_000.0
+                        step=25_000.0
+                        format_value=format_currency
+                        field="home_sale_exclusion"
+                        inputs=inputs
+                    />
+
+                    <SliderInput
+                        label="Ownership Needed for Exclusion"
+                        value=exclusion_min_years
+                        set_value=set_exclusion_min_years
+                        min=0.0
+                        max=5.0
+                        step=1.0
+                        format_value=|v| format!("{} years", v as u32)
+                        field="exclusion_min_years"
+                        inputs=inputs
+                    />
+                </div>
+            </div>
+
+            <NetWorthChart result=result time_horizon=time_horizon_years />
+
+            <MonteCarloSection inputs=inputs />
+
+            <BacktestSection inputs=inputs />
+
+            <HeatmapSection inputs=inputs />
+
+            <TornadoSection inputs=inputs />
+
+            <BreakdownSection result=result />
+        </div>
+    }
+}
+
+fn format_currency(value: f64) -> String {
+    let abs_value = value.abs();
+    let sign = if value < 0.0 { "-" } else { "" };
+    if abs_value >= 1_000_000.0 {
+        format!("{}${:.2}M", sign, abs_value / 1_000_000.0)
+    } else if abs_value >= 1_000.0 {
+        format!("{}${:.0}K", sign, abs_value / 1_000.0)
+    } else {
+        format!("{}${:.0}", sign, abs_value)
+    }
+}
+
+/// More precise currency format for sensitivity labels
+fn format_currency_precise(value: f64) -> String {
+    let abs_value = value.abs().round() as i64;
+    let sign = if value < 0.0 { "-" } else { "" };
+    if abs_value >= 1_000_000 {
+        let millions = value.abs() / 1_000_000.0;
+        format!("{}${:.2}M", sign, millions)
+    } else {
+        let formatted = abs_value
+            .to_string()
             .as_bytes()
             .rchunks(3)
             .rev()
@@ -389,6 +1430,11 @@ fn parse_bound_value(input: &str) -> Result<f64, ()> {
     }
 }
 
+/// Format a simulation month (1-based) as "Year X, Month Y"
+fn format_month(month: u32) -> String {
+    format!("Year {}, Month {}", (month - 1) / 12 + 1, (month - 1) % 12 + 1)
+}
+
 fn format_currency_full(value: f64) -> String {
     let abs_value = value.abs().round() as i64;
     let sign = if value < 0.0 { "-" } else { "" };
@@ -404,7 +1450,23 @@ fn format_currency_full(value: f64) -> String {
 }
 
 #[component]
-fn ResultBanner(result: Memo<CalculationResult>) -> impl IntoView {
+fn ResultBanner(
+    result: Memo<CalculationResult>,
+    real_dollars: ReadSignal<bool>,
+    inputs: Memo<Inputs>,
+) -> impl IntoView {
+    // Rent at which buying and renting come out even, searched well beyond the slider range.
+    // The search runs a few hundred simulations, so it's done on request and cleared on any change
+    let (break_even_rent, set_break_even_rent) = create_signal(None::<BreakEvenValue>);
+    create_effect(move |_| {
+        inputs.track();
+        set_break_even_rent.set(None);
+    });
+    let find_break_even_rent = move |_| {
+        let rent = calculations::solve_break_even(&inputs.get_untracked(), "monthly_rent", 100.0, 20_000.0, 1.0);
+        set_break_even_rent.set(Some(rent));
+    };
+
     let banner_class = move || {
         if result.get().difference > 0.0 {
             "result-banner buy-wins"
@@ -437,12 +1499,38 @@ fn ResultBanner(result: Memo<CalculationResult>) -> impl IntoView {
                 {move || {
                     let r = result.get();
                     format!(
-                        "Buy net worth: {} | Rent net worth: {}",
+                        "Buy net worth: {} | Rent net worth: {}{}",
                         format_currency_full(r.buy_breakdown.net_worth),
-                        format_currency_full(r.rent_breakdown.net_worth)
+                        format_currency_full(r.rent_breakdown.net_worth),
+                        if real_dollars.get() { " (today's dollars)" } else { "" }
                     )
                 }}
             </div>
+            <div class="result-detail">
+                {move || {
+                    let r = result.get();
+                    match r.break_even {
+                        Some(b) if b.month == 1 => "Buying is ahead from the first month".to_string(),
+                        Some(b) => format!("Buying pulls ahead for good in {}", format_month(b.month)),
+                        None => format!("Buying doesn't pull ahead within {} years", r.yearly_snapshots.len()),
+                    }
+                }}
+            </div>
+            <div class="result-detail">
+                {move || match break_even_rent.get() {
+                    Some(BreakEvenValue::Unique(rent)) => format!("Break-even rent: {}/mo", format_currency_full(rent)).into_view(),
+                    Some(BreakEvenValue::Multiple(rents)) => format!(
+                        "Break-even rent: {}/mo (outcome flips more than once)",
+                        rents.iter().map(|r| format_currency_full(*r)).collect::<Vec<_>>().join(", ")
+                    )
+                    .into_view(),
+                    Some(BreakEvenValue::NoSolution) => "Break-even rent: none between $100 and $20,000/mo".into_view(),
+                    None => view! {
+                        <button class="list-button" on:click=find_break_even_rent>"Find break-even rent"</button>
+                    }
+                    .into_view(),
+                }}
+            </div>
         </div>
     }
 }
@@ -470,6 +1558,11 @@ where
     let (min_input_value, set_min_input_value) = create_signal(format_bound_value(min, step));
     let (max_input_value, set_max_input_value) = create_signal(format_bound_value(max, step));
 
+    if let Some(registry) = use_context::<SliderRegistry>() {
+        registry.register(SliderBounds { field, label, value, min: current_min, max: current_max });
+        on_cleanup(move || registry.unregister(field));
+    }
+
     let sensitivity_data = create_memo(move |_| {
         generate_sensitivity_data(&inputs.get(), field, current_min.get(), current_max.get(), 50)
     });
@@ -624,73 +1717,539 @@ where
 }
 
 #[component]
-fn SensitivityGraph(
-    data: Memo<Vec<(f64, f64)>>,
-    current_value: ReadSignal<f64>,
-    min: ReadSignal<f64>,
-    max: ReadSignal<f64>,
+fn ToggleInput(
+    label: &'static str,
+    value: ReadSignal<bool>,
+    set_value: WriteSignal<bool>,
 ) -> impl IntoView {
-    let segments = move || {
-        let d = data.get();
-        if d.is_empty() {
-            return vec![];
-        }
-
-        // Find the range of differences for color scaling
-        let max_diff = d.iter().map(|(_, diff)| diff.abs()).fold(0.0_f64, f64::max);
-
-        d.iter()
-            .map(|(_, diff)| {
-                let intensity = if max_diff > 0.0 { diff.abs() / max_diff } else { 0.0 };
-                let is_buy_better = *diff > 0.0;
-                (is_buy_better, intensity)
-            })
-            .collect::<Vec<_>>()
-    };
-
-    let marker_position = move || {
-        let val = current_value.get();
-        let min_v = min.get();
-        let max_v = max.get();
-        let range = max_v - min_v;
-        let pct = if range > 0.0 { (val - min_v) / range * 100.0 } else { 50.0 };
-        format!("calc({}% - 1px)", pct)
-    };
+    view! {
+        <div class="input-group">
+            <label class="toggle-input">
+                <input
+                    type="checkbox"
+                    prop:checked=move || value.get()
+                    on:change=move |ev| set_value.set(event_target_checked(&ev))
+                />
+                <span class="input-label">{label}</span>
+            </label>
+        </div>
+    }
+}
 
-    let min_label = move || {
-        let d = data.get();
-        if let Some((_, diff)) = d.first() {
-            if *diff > 0.0 {
-                format!("Buy +{}", format_currency_precise(*diff))
-            } else {
-                format!("Rent +{}", format_currency_precise(-*diff))
-            }
-        } else {
-            String::new()
+#[component]
+fn SelectInput<T>(
+    label: &'static str,
+    options: Vec<(T, &'static str)>,
+    value: ReadSignal<T>,
+    set_value: WriteSignal<T>,
+) -> impl IntoView
+where
+    T: Copy + PartialEq + 'static,
+{
+    let selected_index = {
+        let options = options.clone();
+        move || {
+            let v = value.get();
+            options.iter().position(|(o, _)| *o == v).unwrap_or(0).to_string()
         }
     };
 
-    let max_label = move || {
-        let d = data.get();
-        if let Some((_, diff)) = d.last() {
-            if *diff > 0.0 {
-                format!("Buy +{}", format_currency_precise(*diff))
-            } else {
-                format!("Rent +{}", format_currency_precise(-*diff))
-            }
-        } else {
-            String::new()
-        }
-    };
+    let option_views = options
+        .iter()
+        .enumerate()
+        .map(|(i, (_, text))| view! { <option value=i.to_string()>{*text}</option> })
+        .collect_view();
 
     view! {
-        <div class="sensitivity-graph">
+        <div class="input-group">
+            <div class="input-header">
+                <span class="input-label">{label}</span>
+            </div>
+            <select
+                class="select-input"
+                prop:value=selected_index
+                on:change=move |ev| {
+                    if let Some((v, _)) = event_target_value(&ev)
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|i| options.get(i))
+                    {
+                        set_value.set(*v);
+                    }
+                }
+            >
+                {option_views}
+            </select>
+        </div>
+    }
+}
+
+#[component]
+fn ExtraPaymentsEditor(
+    payments: ReadSignal<Vec<ExtraPayment>>,
+    set_payments: WriteSignal<Vec<ExtraPayment>>,
+) -> impl IntoView {
+    view! {
+        <div class="input-group">
+            <div class="input-header">
+                <span class="input-label">"One-Off Payments"</span>
+                <button
+                    class="list-button"
+                    on:click=move |_| set_payments.update(|p| p.push(ExtraPayment { month: 12, amount: 10_000.0 }))
+                >
+                    "+ Add payment"
+                </button>
+            </div>
+            {move || {
+                payments
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, payment)| {
+                        view! {
+                            <div class="list-row">
+                                <label>
+                                    "Month "
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        prop:value=payment.month
+                                        on:change=move |ev| {
+                                            if let Ok(month) = event_target_value(&ev).parse::<u32>() {
+                                                set_payments.update(|p| p[i].month = month.max(1));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Amount $"
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="1000"
+                                        prop:value=payment.amount
+                                        on:change=move |ev| {
+                                            if let Ok(amount) = parse_bound_value(&event_target_value(&ev)) {
+                                                set_payments.update(|p| p[i].amount = amount.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <button
+                                    class="list-button"
+                                    on:click=move |_| set_payments.update(|p| { p.remove(i); })
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn RefinanceEditor(
+    refinances: ReadSignal<Vec<RefinanceEvent>>,
+    set_refinances: WriteSignal<Vec<RefinanceEvent>>,
+) -> impl IntoView {
+    view! {
+        <div class="input-group">
+            <div class="input-header">
+                <span class="input-label">"Refinance Events"</span>
+                <button
+                    class="list-button"
+                    on:click=move |_| set_refinances.update(|r| r.push(RefinanceEvent {
+                        month: 36,
+                        rate: 5.5,
+                        term_years: 30,
+                        closing_costs: 6_000.0,
+                        cash_out: 0.0,
+                    }))
+                >
+                    "+ Add refinance"
+                </button>
+            </div>
+            {move || {
+                refinances
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, refi)| {
+                        view! {
+                            <div class="list-row">
+                                <label>
+                                    "Month "
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        prop:value=refi.month
+                                        on:change=move |ev| {
+                                            if let Ok(month) = event_target_value(&ev).parse::<u32>() {
+                                                set_refinances.update(|r| r[i].month = month.max(1));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Rate % "
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="0.125"
+                                        prop:value=refi.rate
+                                        on:change=move |ev| {
+                                            if let Ok(rate) = event_target_value(&ev).parse::<f64>() {
+                                                set_refinances.update(|r| r[i].rate = rate.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Term "
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        max="40"
+                                        prop:value=refi.term_years
+                                        on:change=move |ev| {
+                                            if let Ok(years) = event_target_value(&ev).parse::<u32>() {
+                                                set_refinances.update(|r| r[i].term_years = years.clamp(1, 40));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Costs $"
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="500"
+                                        prop:value=refi.closing_costs
+                                        on:change=move |ev| {
+                                            if let Ok(costs) = parse_bound_value(&event_target_value(&ev)) {
+                                                set_refinances.update(|r| r[i].closing_costs = costs.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Cash-out $"
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="5000"
+                                        prop:value=refi.cash_out
+                                        on:change=move |ev| {
+                                            if let Ok(cash_out) = parse_bound_value(&event_target_value(&ev)) {
+                                                set_refinances.update(|r| r[i].cash_out = cash_out.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <button
+                                    class="list-button"
+                                    on:click=move |_| set_refinances.update(|r| { r.remove(i); })
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn ImprovementsEditor(
+    projects: ReadSignal<Vec<ImprovementProject>>,
+    set_projects: WriteSignal<Vec<ImprovementProject>>,
+) -> impl IntoView {
+    view! {
+        <div class="input-group">
+            <div class="input-header">
+                <span class="input-label">"Improvement Projects"</span>
+                <button
+                    class="list-button"
+                    on:click=move |_| set_projects.update(|p| p.push(ImprovementProject {
+                        name: "Kitchen remodel".to_string(),
+                        month: 24,
+                        cost: 40_000.0,
+                        funding: FundingSource::Cash,
+                        value_uplift_percent: 5.0,
+                    }))
+                >
+                    "+ Add project"
+                </button>
+            </div>
+            {move || {
+                projects
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, project)| {
+                        let funding_index = FundingSource::ALL
+                            .iter()
+                            .position(|f| *f == project.funding)
+                            .unwrap_or(0)
+                            .to_string();
+                        view! {
+                            <div class="list-row">
+                                <input
+                                    type="text"
+                                    prop:value=project.name
+                                    on:change=move |ev| {
+                                        let name = event_target_value(&ev);
+                                        set_projects.update(|p| p[i].name = name);
+                                    }
+                                />
+                                <label>
+                                    "Month "
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        prop:value=project.month
+                                        on:change=move |ev| {
+                                            if let Ok(month) = event_target_value(&ev).parse::<u32>() {
+                                                set_projects.update(|p| p[i].month = month.max(1));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Cost $"
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="1000"
+                                        prop:value=project.cost
+                                        on:change=move |ev| {
+                                            if let Ok(cost) = parse_bound_value(&event_target_value(&ev)) {
+                                                set_projects.update(|p| p[i].cost = cost.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <select
+                                    prop:value=funding_index
+                                    on:change=move |ev| {
+                                        if let Some(funding) = event_target_value(&ev)
+                                            .parse::<usize>()
+                                            .ok()
+                                            .and_then(|f| FundingSource::ALL.get(f))
+                                        {
+                                            set_projects.update(|p| p[i].funding = *funding);
+                                        }
+                                    }
+                                >
+                                    {FundingSource::ALL
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(f, funding)| view! { <option value=f.to_string()>{funding.label()}</option> })
+                                        .collect_view()}
+                                </select>
+                                <label>
+                                    "Value +% "
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="0.5"
+                                        prop:value=project.value_uplift_percent
+                                        on:change=move |ev| {
+                                            if let Ok(uplift) = event_target_value(&ev).parse::<f64>() {
+                                                set_projects.update(|p| p[i].value_uplift_percent = uplift.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <button
+                                    class="list-button"
+                                    on:click=move |_| set_projects.update(|p| { p.remove(i); })
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn CapexEditor(
+    items: ReadSignal<Vec<CapexItem>>,
+    set_items: WriteSignal<Vec<CapexItem>>,
+) -> impl IntoView {
+    view! {
+        <div class="input-group">
+            <div class="input-header">
+                <span class="input-label">"Replacement Schedule"</span>
+                <span class="list-actions">
+                    <button
+                        class="list-button"
+                        on:click=move |_| set_items.update(|c| c.extend(CapexItem::typical()))
+                    >
+                        "+ Typical items"
+                    </button>
+                    <button
+                        class="list-button"
+                        on:click=move |_| set_items.update(|c| c.push(CapexItem {
+                            name: "Item".to_string(),
+                            cost: 5_000.0,
+                            useful_life_years: 15,
+                            age_years: 0,
+                        }))
+                    >
+                        "+ Add item"
+                    </button>
+                </span>
+            </div>
+            {move || {
+                items
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        view! {
+                            <div class="list-row">
+                                <input
+                                    type="text"
+                                    prop:value=item.name
+                                    on:change=move |ev| {
+                                        let name = event_target_value(&ev);
+                                        set_items.update(|c| c[i].name = name);
+                                    }
+                                />
+                                <label>
+                                    "Cost $"
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        step="500"
+                                        prop:value=item.cost
+                                        on:change=move |ev| {
+                                            if let Ok(cost) = parse_bound_value(&event_target_value(&ev)) {
+                                                set_items.update(|c| c[i].cost = cost.max(0.0));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Life (years) "
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        max="100"
+                                        prop:value=item.useful_life_years
+                                        on:change=move |ev| {
+                                            if let Ok(years) = event_target_value(&ev).parse::<u32>() {
+                                                set_items.update(|c| c[i].useful_life_years = years.clamp(1, 100));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <label>
+                                    "Age now "
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        max="100"
+                                        prop:value=item.age_years
+                                        on:change=move |ev| {
+                                            if let Ok(years) = event_target_value(&ev).parse::<u32>() {
+                                                set_items.update(|c| c[i].age_years = years.min(100));
+                                            }
+                                        }
+                                    />
+                                </label>
+                                <button
+                                    class="list-button"
+                                    on:click=move |_| set_items.update(|c| { c.remove(i); })
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn SensitivityGraph(
+    data: Memo<Vec<(f64, f64)>>,
+    current_value: ReadSignal<f64>,
+    min: ReadSignal<f64>,
+    max: ReadSignal<f64>,
+) -> impl IntoView {
+    let segments = move || {
+        let d = data.get();
+        if d.is_empty() {
+            return vec![];
+        }
+
+        // Find the range of differences for color scaling
+        let max_diff = d.iter().map(|(_, diff)| diff.abs()).fold(0.0_f64, f64::max);
+
+        d.iter()
+            .map(|(_, diff)| {
+                let intensity = if max_diff > 0.0 { diff.abs() / max_diff } else { 0.0 };
+                let is_buy_better = *diff > 0.0;
+                (is_buy_better, intensity)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let marker_position = move || {
+        let val = current_value.get();
+        let min_v = min.get();
+        let max_v = max.get();
+        let range = max_v - min_v;
+        let pct = if range > 0.0 { (val - min_v) / range * 100.0 } else { 50.0 };
+        format!("calc({}% - 1px)", pct)
+    };
+
+    let min_label = move || {
+        let d = data.get();
+        if let Some((_, diff)) = d.first() {
+            if *diff > 0.0 {
+                format!("Buy +{}", format_currency_precise(*diff))
+            } else {
+                format!("Rent +{}", format_currency_precise(-*diff))
+            }
+        } else {
+            String::new()
+        }
+    };
+
+    let max_label = move || {
+        let d = data.get();
+        if let Some((_, diff)) = d.last() {
+            if *diff > 0.0 {
+                format!("Buy +{}", format_currency_precise(*diff))
+            } else {
+                format!("Rent +{}", format_currency_precise(-*diff))
+            }
+        } else {
+            String::new()
+        }
+    };
+
+    view! {
+        <div class="sensitivity-graph">
             <div class="sensitivity-bar">
                 {move || {
                     segments()
                         .into_iter()
-                        .enumerate()
-                        .map(|(_i, (is_buy, intensity))| {
+                        .map(|(is_buy, intensity)| {
                             let color = if is_buy {
                                 format!("rgba(37, 99, 235, {})", 0.2 + intensity * 0.8)
                             } else {
@@ -745,7 +2304,12 @@ fn NetWorthChart(result: Memo<CalculationResult>, time_horizon: ReadSignal<f64>)
             .map(|s| s.rent_net_worth)
             .collect();
 
-        call_create_or_update_chart(canvas_id, &labels, &buy_data, &rent_data);
+        let details: Vec<YearlySnapshot> = snapshots.iter().take(years).cloned().collect();
+
+        // Only mark the crossing if it happens within the years shown
+        let break_even_month = r.break_even.map(|b| b.month).filter(|&m| m <= years as u32 * 12);
+
+        call_create_or_update_chart(canvas_id, &labels, &buy_data, &rent_data, &details, break_even_month);
     });
 
     view! {
@@ -754,6 +2318,567 @@ fn NetWorthChart(result: Memo<CalculationResult>, time_horizon: ReadSignal<f64>)
             <div class="chart-container">
                 <canvas id=canvas_id></canvas>
             </div>
+            <YearlyTable result=result />
+        </div>
+    }
+}
+
+#[component]
+fn YearlyTable(result: Memo<CalculationResult>) -> impl IntoView {
+    let (expanded, set_expanded) = create_signal(false);
+
+    let rows = move || {
+        result.with(|r| {
+            r.yearly_snapshots
+                .iter()
+                .map(|s| {
+                    view! {
+                        <tr>
+                            <td>{s.year}</td>
+                            <td>{format_currency_full(s.home_value)}</td>
+                            <td>{format_currency_full(s.mortgage_balance)}</td>
+                            <td>{format_currency_full(s.equity)}</td>
+                            <td>{format_currency_full(s.selling_costs)}</td>
+                            <td>{format_currency_full(s.home_sale_tax)}</td>
+                            <td>{format_currency_full(s.buyer_portfolio)}</td>
+                            <td>{format_currency_full(s.buyer_portfolio_tax)}</td>
+                            <td class="buy">{format_currency_full(s.buy_net_worth)}</td>
+                            <td>{format_currency_full(s.renter_portfolio)}</td>
+                            <td>{format_currency_full(s.renter_portfolio_tax)}</td>
+                            <td class="rent">{format_currency_full(s.rent_net_worth)}</td>
+                            <td>{format_currency_full(s.buy_cumulative_spend)}</td>
+                            <td>{format_currency_full(s.rent_cumulative_spend)}</td>
+                            <td>{format_currency_full(s.buy_monthly_cost)}</td>
+                            <td>{format_currency_full(s.current_rent)}</td>
+                        </tr>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="schedule-header">
+            <span class="input-label">"Year-by-Year Breakdown"</span>
+            <button class="list-button" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                {move || if expanded.get() { "Hide" } else { "Show" }}
+            </button>
+        </div>
+        <Show when=move || expanded.get()>
+            <div class="schedule-table-wrapper">
+                <table class="schedule-table">
+                    <thead>
+                        <tr>
+                            <th>"Year"</th>
+                            <th>"Home Value"</th>
+                            <th>"Mortgage"</th>
+                            <th>"Equity"</th>
+                            <th>"Selling Costs"</th>
+                            <th>"Sale Tax"</th>
+                            <th>"Buyer Portfolio"</th>
+                            <th>"Portfolio Tax"</th>
+                            <th>"Buy Net Worth"</th>
+                            <th>"Renter Portfolio"</th>
+                            <th>"Portfolio Tax"</th>
+                            <th>"Rent Net Worth"</th>
+                            <th>"Buy Spend to Date"</th>
+                            <th>"Rent Spend to Date"</th>
+                            <th>"Buy Cost/mo"</th>
+                            <th>"Rent/mo"</th>
+                        </tr>
+                    </thead>
+                    <tbody>{rows}</tbody>
+                </table>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn MonteCarloSection(inputs: Memo<Inputs>) -> impl IntoView {
+    let canvas_id = "monte-carlo-chart";
+    let (settings, set_settings) = create_signal(MonteCarloSettings::from_inputs(&inputs.get_untracked()));
+    let (mc_result, set_mc_result) = create_signal(None::<MonteCarloResult>);
+    let (stale, set_stale) = create_signal(false);
+
+    // A run reflects the inputs at the time; flag it once they change
+    create_effect(move |_| {
+        inputs.track();
+        set_stale.set(true);
+    });
+
+    // The means follow the deterministic assumptions; only the spread is set here
+    let run = move |_| {
+        let i = inputs.get_untracked();
+        let s = settings.get_untracked();
+        let s = MonteCarloSettings {
+            home_appreciation: RateDistribution { mean: i.home_appreciation, ..s.home_appreciation },
+            rent_increase: RateDistribution { mean: i.rent_increase_rate, ..s.rent_increase },
+            investment_return: RateDistribution { mean: MarketPath::neutral_investment_return(&i), ..s.investment_return },
+            ..s
+        };
+        set_mc_result.set(Some(monte_carlo::run_monte_carlo(&i, &s)));
+        set_stale.set(false);
+    };
+
+    create_effect(move |_| {
+        if let Some(r) = mc_result.get() {
+            let labels: Vec<String> = (1..=r.buy_net_worth.len())
+                .map(|y| format!("Year {}", y))
+                .collect();
+            call_create_or_update_fan_chart(canvas_id, &labels, &r.buy_net_worth, &r.rent_net_worth);
+        }
+    });
+
+    let setting_input = move |label: &'static str, step: &'static str, get: fn(&MonteCarloSettings) -> f64, put: fn(&mut MonteCarloSettings, f64)| {
+        view! {
+            <label>
+                {label}
+                <input
+                    type="number"
+                    step=step
+                    prop:value=move || get(&settings.get())
+                    on:change=move |ev| {
+                        if let Ok(v) = parse_bound_value(&event_target_value(&ev)) {
+                            set_settings.update(|s| put(s, v));
+                        }
+                    }
+                />
+            </label>
+        }
+    };
+
+    view! {
+        <div class="chart-section">
+            <div class="section-title schedule-header">
+                <span>"Monte Carlo Simulation"</span>
+                <button class="list-button" on:click=run>"Run simulation"</button>
+            </div>
+            <div class="list-row">
+                {setting_input("Paths ", "500", |s| s.simulations as f64, |s, v| s.simulations = v.clamp(1.0, monte_carlo::MAX_SIMULATIONS as f64) as usize)}
+                {setting_input("Seed ", "1", |s| s.seed as f64, |s, v| s.seed = v.max(0.0) as u64)}
+                {setting_input("Home vol % ", "0.5", |s| s.home_appreciation.volatility, |s, v| s.home_appreciation.volatility = v.max(0.0))}
+                {setting_input("Rent vol % ", "0.5", |s| s.rent_increase.volatility, |s, v| s.rent_increase.volatility = v.max(0.0))}
+                {setting_input("Stock vol % ", "0.5", |s| s.investment_return.volatility, |s, v| s.investment_return.volatility = v.max(0.0))}
+                {setting_input("Home/stock correlation ", "0.1", |s| s.home_stock_correlation, |s, v| s.home_stock_correlation = v.clamp(-1.0, 1.0))}
+            </div>
+            <div class="result-detail">
+                {move || match mc_result.get() {
+                    Some(r) => format!(
+                        "Buying wins in {:.0}% of {} paths | Final difference (buy - rent): 10th {} | median {} | 90th {}{}",
+                        r.buy_win_probability * 100.0,
+                        r.simulations,
+                        format_currency_full(r.difference.p10),
+                        format_currency_full(r.difference.p50),
+                        format_currency_full(r.difference.p90),
+                        if stale.get() { " (inputs have changed; run again to update)" } else { "" },
+                    ),
+                    None => "Sample appreciation, rent growth and returns each year to see the range of outcomes.".to_string(),
+                }}
+            </div>
+            <div class="chart-container">
+                <canvas id=canvas_id></canvas>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn BacktestSection(inputs: Memo<Inputs>) -> impl IntoView {
+    let (expanded, set_expanded) = create_signal(false);
+
+    // Only replay history while the section is open
+    let backtest = create_memo(move |_| {
+        if expanded.get() {
+            backtest::run_backtest(&inputs.get())
+        } else {
+            Default::default()
+        }
+    });
+
+    let summary = move || {
+        let b = backtest.get();
+        let (Some(first), Some(last), Some(best), Some(worst)) = (b.runs.first(), b.runs.last(), &b.best, &b.worst) else {
+            return view! {
+                <div class="result-detail">"Not enough history for this time horizon."</div>
+            }
+            .into_view();
+        };
+        view! {
+            <div class="breakdown-item">
+                <span class="label">
+                    {format!("Buying won ({} start years, {}-{})", b.runs.len(), first.start_year, last.start_year)}
+                </span>
+                <span class="value">{format!("{:.0}%", b.buy_win_share * 100.0)}</span>
+            </div>
+            <div class="breakdown-item">
+                <span class="label">"Buy - rent (10th / median / 90th)"</span>
+                <span class="value">
+                    {format!(
+                        "{} / {} / {}",
+                        format_currency_full(b.difference.p10),
+                        format_currency_full(b.difference.p50),
+                        format_currency_full(b.difference.p90),
+                    )}
+                </span>
+            </div>
+            <div class="breakdown-item">
+                <span class="label">{format!("Best start year for buying: {}", best.start_year)}</span>
+                <span class="value positive">{format_currency_full(best.difference)}</span>
+            </div>
+            <div class="breakdown-item">
+                <span class="label">{format!("Worst start year for buying: {}", worst.start_year)}</span>
+                <span class="value negative">{format_currency_full(worst.difference)}</span>
+            </div>
+        }
+        .into_view()
+    };
+
+    let rows = move || {
+        backtest.with(|b| {
+            b.runs
+                .iter()
+                .map(|run| {
+                    let class = if run.difference > 0.0 { "buy" } else { "rent" };
+                    view! {
+                        <tr>
+                            <td>{run.start_year}</td>
+                            <td>{format!("{:.2}%", run.mortgage_rate)}</td>
+                            <td>{format_currency_full(run.buy_net_worth)}</td>
+                            <td>{format_currency_full(run.rent_net_worth)}</td>
+                            <td class=class>{format_currency_full(run.difference)}</td>
+                        </tr>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="chart-section">
+            <div class="section-title schedule-header">
+                <span>"Historical Backtest"</span>
+                <button class="list-button" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                    {move || if expanded.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            <div class="result-detail">
+                "Replays your inputs with the home prices, stock returns, rents and mortgage rates of every start year since 1975 (approximate US history)."
+            </div>
+            <Show when=move || expanded.get()>
+                {summary}
+                <div class="schedule-table-wrapper">
+                    <table class="schedule-table">
+                        <thead>
+                            <tr>
+                                <th>"Start Year"</th>
+                                <th>"Mortgage Rate"</th>
+                                <th>"Buy Net Worth"</th>
+                                <th>"Rent Net Worth"</th>
+                                <th>"Buy - Rent"</th>
+                            </tr>
+                        </thead>
+                        <tbody>{rows}</tbody>
+                    </table>
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+const HEATMAP_STEPS: usize = 16;
+const HEATMAP_CELL: f64 = 20.0;
+
+#[component]
+fn HeatmapSection(inputs: Memo<Inputs>) -> impl IntoView {
+    let registry = expect_context::<SliderRegistry>();
+    let (expanded, set_expanded) = create_signal(false);
+    let (x_field, set_x_field) = create_signal("mortgage_rate");
+    let (y_field, set_y_field) = create_signal("home_appreciation");
+
+    // Each axis spans its slider's current bounds
+    let grid = create_memo(move |_| {
+        if !expanded.get() {
+            return None;
+        }
+        let x = registry.get(x_field.get())?;
+        let y = registry.get(y_field.get())?;
+        Some(generate_sensitivity_grid(
+            &inputs.get(),
+            x.field,
+            x.min.get(),
+            x.max.get(),
+            y.field,
+            y.min.get(),
+            y.max.get(),
+            HEATMAP_STEPS,
+        ))
+    });
+
+    let axis_select = move |value: ReadSignal<&'static str>, set_value: WriteSignal<&'static str>| {
+        view! {
+            <select
+                class="select-input"
+                prop:value=move || value.get()
+                on:change=move |ev| {
+                    let chosen = event_target_value(&ev);
+                    if let Some(s) = registry.get(&chosen) {
+                        set_value.set(s.field);
+                    }
+                }
+            >
+                {move || {
+                    registry
+                        .0
+                        .get()
+                        .into_iter()
+                        .map(|s| view! { <option value=s.field selected=s.field == value.get_untracked()>{s.label}</option> })
+                        .collect_view()
+                }}
+            </select>
+        }
+    };
+
+    let heatmap = move || {
+        let Some(grid) = grid.get() else {
+            return view! { <div class="result-detail">"Pick two sliders to compare."</div> }.into_view();
+        };
+        let columns = grid.x_values.len();
+        let rows = grid.y_values.len();
+        let width = columns as f64 * HEATMAP_CELL;
+        let height = rows as f64 * HEATMAP_CELL;
+        let largest = grid
+            .differences
+            .iter()
+            .flatten()
+            .fold(0.0_f64, |m, d| m.max(d.abs()))
+            .max(1.0);
+
+        // Higher y values are drawn at the top
+        let to_svg = |(x, y): (f64, f64)| ((x + 0.5) * HEATMAP_CELL, height - (y + 0.5) * HEATMAP_CELL);
+
+        let cells = grid
+            .differences
+            .iter()
+            .enumerate()
+            .flat_map(|(yi, row)| {
+                let grid = &grid;
+                row.iter().enumerate().map(move |(xi, &d)| {
+                    let alpha = 0.1 + 0.8 * d.abs() / largest;
+                    let fill = if d > 0.0 {
+                        format!("rgba(37, 99, 235, {:.2})", alpha)
+                    } else {
+                        format!("rgba(220, 38, 38, {:.2})", alpha)
+                    };
+                    let (cx, cy) = to_svg((xi as f64, yi as f64));
+                    view! {
+                        <rect
+                            x=cx - HEATMAP_CELL / 2.0
+                            y=cy - HEATMAP_CELL / 2.0
+                            width=HEATMAP_CELL
+                            height=HEATMAP_CELL
+                            fill=fill
+                        >
+                            <title>
+                                {format!(
+                                    "{} x {}: {}",
+                                    format_bound_display(grid.x_values[xi]),
+                                    format_bound_display(grid.y_values[yi]),
+                                    format_currency_full(d),
+                                )}
+                            </title>
+                        </rect>
+                    }
+                })
+            })
+            .collect_view();
+
+        let contour = grid
+            .zero_contour()
+            .into_iter()
+            .map(|(a, b)| {
+                let (x1, y1) = to_svg(a);
+                let (x2, y2) = to_svg(b);
+                view! { <line x1=x1 y1=y1 x2=x2 y2=y2 class="heatmap-contour" /> }
+            })
+            .collect_view();
+
+        let x_range = format!(
+            "{} \u{2192} {}",
+            format_bound_display(grid.x_values[0]),
+            format_bound_display(grid.x_values[columns - 1])
+        );
+        let y_range = format!(
+            "{} \u{2192} {} (bottom to top)",
+            format_bound_display(grid.y_values[0]),
+            format_bound_display(grid.y_values[rows - 1])
+        );
+
+        view! {
+            <svg class="heatmap" viewBox=format!("0 0 {} {}", width, height)>
+                {cells}
+                {contour}
+            </svg>
+            <div class="sensitivity-labels">
+                <span>{format!("Across: {}", x_range)}</span>
+                <span>{format!("Up: {}", y_range)}</span>
+            </div>
+        }
+        .into_view()
+    };
+
+    view! {
+        <div class="chart-section">
+            <div class="section-title schedule-header">
+                <span>"Two-Way Sensitivity"</span>
+                <button class="list-button" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                    {move || if expanded.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            <div class="result-detail">
+                "Blue cells favor buying, red cells favor renting; the black line is where they tie."
+            </div>
+            <Show when=move || expanded.get()>
+                <div class="input-row">
+                    <div class="input-group">
+                        <div class="input-header">
+                            <span class="input-label">"Across"</span>
+                        </div>
+                        {axis_select(x_field, set_x_field)}
+                    </div>
+                    <div class="input-group">
+                        <div class="input-header">
+                            <span class="input-label">"Up"</span>
+                        </div>
+                        {axis_select(y_field, set_y_field)}
+                    </div>
+                </div>
+                {heatmap}
+            </Show>
+        </div>
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TornadoRange {
+    SliderBounds,
+    Percent,
+}
+
+#[component]
+fn TornadoSection(inputs: Memo<Inputs>) -> impl IntoView {
+    let registry = expect_context::<SliderRegistry>();
+    let (expanded, set_expanded) = create_signal(false);
+    let (range_mode, set_range_mode) = create_signal(TornadoRange::SliderBounds);
+    let (percent, set_percent) = create_signal(20.0);
+
+    let analysis = create_memo(move |_| {
+        if !expanded.get() {
+            return (0.0, Vec::new());
+        }
+        let pct = percent.get() / 100.0;
+        let ranges: Vec<(&str, f64, f64)> = registry.0.with(|sliders| {
+            sliders
+                .iter()
+                .map(|s| match range_mode.get() {
+                    TornadoRange::SliderBounds => (s.field, s.min.get(), s.max.get()),
+                    TornadoRange::Percent => {
+                        let v = s.value.get();
+                        (s.field, v - v.abs() * pct, v + v.abs() * pct)
+                    }
+                })
+                .collect()
+        });
+        let i = inputs.get();
+        let base = calculations::calculate_with_path(&i, &MarketPath::default(), Detail::Summary).difference;
+        (base, tornado_analysis(&i, &ranges))
+    });
+
+    let bars = move || {
+        let (base, bars) = analysis.get();
+        // Bars are drawn around the current outcome; the widest reaches the edge
+        let extent = bars
+            .iter()
+            .flat_map(|b| [b.low_difference, b.high_difference])
+            .fold(0.0_f64, |m, d| m.max((d - base).abs()))
+            .max(1.0);
+        let position = move |d: f64| 50.0 + (d - base) / extent * 50.0;
+        let segment = move |d: f64| {
+            let (from, to) = (position(base), position(d));
+            format!("left: {:.2}%; width: {:.2}%", from.min(to), (to - from).abs())
+        };
+
+        bars.into_iter()
+            .filter(|b| b.swing() > 0.0)
+            .map(|b| {
+                let label = registry.get(&b.field).map_or("", |s| s.label);
+                let title = format!(
+                    "{} to {}: {} to {}",
+                    format_bound_display(b.low),
+                    format_bound_display(b.high),
+                    format_currency_full(b.low_difference),
+                    format_currency_full(b.high_difference),
+                );
+                view! {
+                    <div class="tornado-row" title=title>
+                        <span class="tornado-label">{label}</span>
+                        <div class="tornado-track">
+                            <div class="tornado-bar low" style=segment(b.low_difference)></div>
+                            <div class="tornado-bar high" style=segment(b.high_difference)></div>
+                            <div class="tornado-center"></div>
+                        </div>
+                        <span class="tornado-swing">{format_currency_full(b.swing())}</span>
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="chart-section">
+            <div class="section-title schedule-header">
+                <span>"What Drives the Result"</span>
+                <button class="list-button" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                    {move || if expanded.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            <div class="result-detail">
+                "Each bar moves one assumption from low (gray) to high (dark) with the rest held; the center line is the current result."
+            </div>
+            <Show when=move || expanded.get()>
+                <div class="input-row">
+                    <SelectInput
+                        label="Range"
+                        options=vec![
+                            (TornadoRange::SliderBounds, "Slider minimum to maximum"),
+                            (TornadoRange::Percent, "Percent around the current value"),
+                        ]
+                        value=range_mode
+                        set_value=set_range_mode
+                    />
+                    <Show when=move || range_mode.get() == TornadoRange::Percent>
+                        <div class="input-group">
+                            <div class="input-header">
+                                <span class="input-label">"Plus or minus"</span>
+                                <span class="input-value">{move || format!("{:.0}%", percent.get())}</span>
+                            </div>
+                            <input
+                                type="range"
+                                min="5"
+                                max="50"
+                                step="5"
+                                prop:value=move || percent.get()
+                                on:input=move |ev| {
+                                    if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+                                        set_percent.set(v);
+                                    }
+                                }
+                            />
+                        </div>
+                    </Show>
+                </div>
+                {bars}
+            </Show>
         </div>
     }
 }
@@ -818,10 +2943,108 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"  └ Principal Paid"</span>
                         <span class="value">{move || format_currency_full(result.get().buy_breakdown.total_principal_paid)}</span>
                     </div>
+                    {move || {
+                        let b = result.get().buy_breakdown;
+                        if b.extra_principal_paid > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"  └ Extra Principal"</span>
+                                    <span class="value">{format_currency_full(b.extra_principal_paid)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Interest Saved (Life of Loan)"</span>
+                                    <span class="value positive">{format_currency_full(b.interest_saved)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Loan Paid Off"</span>
+                                    <span class="value">{format_month(b.payoff_month)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let b = result.get().buy_breakdown;
+                        if b.points_cost > 0.0 {
+                            let break_even = match b.points_break_even_month {
+                                Some(month) => format!("  └ Points break even {}", format_month(month)),
+                                None => "  └ Points don't pay back before the loan ends or is refinanced".to_string(),
+                            };
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Discount Points"</span>
+                                    <span class="value negative">{format_currency_full(b.points_cost)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">{break_even}</span>
+                                    <span class="value"></span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let subsidy = result.get().buy_breakdown.buy_down_subsidy;
+                        if subsidy > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Buy-Down Subsidy"</span>
+                                    <span class="value positive">{format_currency_full(subsidy)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        result
+                            .get()
+                            .buy_breakdown
+                            .refinances
+                            .into_iter()
+                            .map(|refi| {
+                                let break_even = match refi.break_even_month {
+                                    Some(month) => format!("breaks even {}", format_month(month)),
+                                    None => "never breaks even".to_string(),
+                                };
+                                view! {
+                                    <div class="breakdown-item">
+                                        <span class="label">{format!("Refinance ({})", format_month(refi.month))}</span>
+                                        <span class="value negative">{format_currency_full(refi.closing_costs)}</span>
+                                    </div>
+                                    <div class="breakdown-item">
+                                        <span class="label">
+                                            {format!("  └ New payment ${:.0}/mo, {}", refi.new_payment, break_even)}
+                                        </span>
+                                        <span class="value"></span>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <div class="breakdown-item">
+                        <span class="label">"PMI"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.total_pmi)}</span>
+                    </div>
                     <div class="breakdown-item">
                         <span class="label">"Property Taxes"</span>
                         <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.total_property_tax)}</span>
                     </div>
+                    {move || {
+                        let b = result.get().buy_breakdown;
+                        if (b.final_assessed_value - b.final_home_value).abs() >= 1.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"  └ Assessed Value at End"</span>
+                                    <span class="value">{format_currency_full(b.final_assessed_value)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
                     <div class="breakdown-item">
                         <span class="label">"Home Insurance"</span>
                         <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.total_insurance)}</span>
@@ -834,18 +3057,147 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"Maintenance"</span>
                         <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.total_maintenance)}</span>
                     </div>
+                    {move || {
+                        result
+                            .get()
+                            .buy_breakdown
+                            .capex
+                            .into_iter()
+                            .map(|replacement| {
+                                view! {
+                                    <div class="breakdown-item">
+                                        <span class="label">
+                                            {format!("{} ({})", replacement.name, format_month(replacement.month))}
+                                        </span>
+                                        <span class="value negative">{format_currency_full(replacement.cost)}</span>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    {move || {
+                        result
+                            .get()
+                            .buy_breakdown
+                            .improvements
+                            .into_iter()
+                            .map(|project| {
+                                view! {
+                                    <div class="breakdown-item">
+                                        <span class="label">
+                                            {format!("{} ({})", project.name, format_month(project.month))}
+                                        </span>
+                                        <span class="value negative">{format_currency_full(project.cost)}</span>
+                                    </div>
+                                    <div class="breakdown-item">
+                                        <span class="label">
+                                            {format!(
+                                                "  └ Paid with {}, adds {} to the home's value",
+                                                project.funding.label(),
+                                                format_currency_full(project.value_added),
+                                            )}
+                                        </span>
+                                        <span class="value"></span>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
                     <div class="breakdown-item">
                         <span class="label">"Selling Costs"</span>
                         <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.selling_costs)}</span>
                     </div>
+                    <div class="breakdown-item">
+                        <span class="label">"Capital Gains Tax on Sale"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.home_sale_tax)}</span>
+                    </div>
+                    {move || {
+                        let recapture = result.get().buy_breakdown.depreciation_recapture_tax;
+                        if recapture > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"  └ Depreciation Recapture"</span>
+                                    <span class="value">{format_currency_full(recapture)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    <div class="breakdown-item">
+                        <span class="label">"  (Cost basis)"</span>
+                        <span class="value">{move || format_currency_full(result.get().buy_breakdown.home_cost_basis)}</span>
+                    </div>
                     <div class="breakdown-item">
                         <span class="label">"Final Home Value"</span>
                         <span class="value positive">{move || format_currency_full(result.get().buy_breakdown.final_home_value)}</span>
                     </div>
+                    <Show when=move || result.get().buy_breakdown.kept_as_rental>
+                        <div class="breakdown-item">
+                            <span class="label">"  (Kept as a rental, so not sold)"</span>
+                            <span class="value"></span>
+                        </div>
+                    </Show>
                     <div class="breakdown-item">
                         <span class="label">"Remaining Mortgage"</span>
                         <span class="value negative">{move || format_currency_full(result.get().buy_breakdown.remaining_mortgage)}</span>
                     </div>
+                    {move || {
+                        let b = result.get().buy_breakdown;
+                        if b.heloc_payments > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"HELOC Payments"</span>
+                                    <span class="value negative">{format_currency_full(b.heloc_payments)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">{format!("  └ Interest {}", format_currency_full(b.heloc_interest))}</span>
+                                    <span class="value"></span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Remaining HELOC"</span>
+                                    <span class="value negative">{format_currency_full(b.remaining_heloc)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+
+                    <div class="breakdown-item">
+                        <span class="label">"Tax Savings (Itemizing)"</span>
+                        <span class="value positive">{move || format_currency_full(result.get().buy_breakdown.tax_savings)}</span>
+                    </div>
+                    {move || {
+                        let b = result.get().buy_breakdown;
+                        if b.rental_income > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Rental Income"</span>
+                                    <span class="value positive">{format_currency_full(b.rental_income)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Tax on Rental Income"</span>
+                                    <span class="value negative">{format_currency_full(b.rental_income_tax)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let rent = result.get().buy_breakdown.rent_after_move_out;
+                        if rent > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Rent Paid After Moving Out"</span>
+                                    <span class="value negative">{format_currency_full(rent)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
 
                     {move || {
                         let savings = result.get().buy_breakdown.monthly_savings_invested;
@@ -859,9 +3211,31 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                                     <span class="label">"  (Because buying cost less)"</span>
                                     <span class="value"></span>
                                 </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+
+                    {move || {
+                        let r = result.get();
+                        if r.buy_breakdown.investment_balance > 0.0 {
+                            view! {
                                 <div class="breakdown-item">
                                     <span class="label">"Investment Returns"</span>
-                                    <span class="value positive">{format_currency_full(result.get().buy_breakdown.investment_returns)}</span>
+                                    <span class="value positive">{format_currency_full(r.buy_breakdown.investment_returns)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Dividend Taxes"</span>
+                                    <span class="value negative">{format_currency_full(r.buy_breakdown.investment_tax_drag)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Fund & Advisory Fees"</span>
+                                    <span class="value negative">{format_currency_full(r.buy_breakdown.investment_fees)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"Tax on Liquidation"</span>
+                                    <span class="value negative">{format_currency_full(r.buy_breakdown.investment_liquidation_tax)}</span>
                                 </div>
                             }.into_view()
                         } else {
@@ -883,7 +3257,7 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="value">{move || format_currency_full(result.get().rent_breakdown.initial_investment)}</span>
                     </div>
                     <div class="breakdown-item">
-                        <span class="label">"  (Down payment + closing costs)"</span>
+                        <span class="label">"  (Buyer's cash at closing, less deposit and fees)"</span>
                         <span class="value"></span>
                     </div>
 
@@ -909,6 +3283,18 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"Investment Returns"</span>
                         <span class="value positive">{move || format_currency_full(result.get().rent_breakdown.investment_returns)}</span>
                     </div>
+                    <div class="breakdown-item">
+                        <span class="label">"Dividend Taxes"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.investment_tax_drag)}</span>
+                    </div>
+                    <div class="breakdown-item">
+                        <span class="label">"Fund & Advisory Fees"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.investment_fees)}</span>
+                    </div>
+                    <div class="breakdown-item">
+                        <span class="label">"Tax on Liquidation"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.investment_liquidation_tax)}</span>
+                    </div>
                     <div class="breakdown-item">
                         <span class="label">"Total Rent Paid"</span>
                         <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.total_rent_paid)}</span>
@@ -917,9 +3303,30 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"Renter's Insurance"</span>
                         <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.total_renters_insurance)}</span>
                     </div>
+                    <div class="breakdown-item">
+                        <span class="label">"Application & Broker Fees"</span>
+                        <span class="value negative">{move || format_currency_full(result.get().rent_breakdown.rental_fees)}</span>
+                    </div>
+                    {move || {
+                        let r = result.get().rent_breakdown;
+                        if r.moves > 0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">{format!("Moving Costs ({} moves)", r.moves)}</span>
+                                    <span class="value negative">{format_currency_full(r.moving_costs)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    <div class="breakdown-item">
+                        <span class="label">"Security Deposit Returned"</span>
+                        <span class="value positive">{move || format_currency_full(result.get().rent_breakdown.security_deposit)}</span>
+                    </div>
 
                     <div class="breakdown-item total">
-                        <span class="label">"Net Worth (Investments)"</span>
+                        <span class="label">"Net Worth (Investments + Deposit)"</span>
                         <span class="value">{move || format_currency_full(result.get().rent_breakdown.net_worth)}</span>
                     </div>
                 </div>
@@ -936,6 +3343,27 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"Mortgage Payment"</span>
                         <span class="value">{move || format!("${:.0}", result.get().monthly_breakdown.buy_mortgage)}</span>
                     </div>
+                    {move || {
+                        let m = result.get().monthly_breakdown;
+                        if (m.buy_mortgage_peak - m.buy_mortgage_initial).abs() >= 0.5 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"  └ Starting Payment"</span>
+                                    <span class="value">{format!("${:.0}", m.buy_mortgage_initial)}</span>
+                                </div>
+                                <div class="breakdown-item">
+                                    <span class="label">"  └ Peak Payment"</span>
+                                    <span class="value">{format!("${:.0}", m.buy_mortgage_peak)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    <div class="breakdown-item">
+                        <span class="label">"PMI"</span>
+                        <span class="value">{move || format!("${:.0}", result.get().monthly_breakdown.buy_pmi)}</span>
+                    </div>
                     <div class="breakdown-item">
                         <span class="label">"Property Tax"</span>
                         <span class="value">{move || format!("${:.0}", result.get().monthly_breakdown.buy_property_tax)}</span>
@@ -952,6 +3380,58 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                         <span class="label">"Maintenance"</span>
                         <span class="value">{move || format!("${:.0}", result.get().monthly_breakdown.buy_maintenance)}</span>
                     </div>
+                    {move || {
+                        let heloc = result.get().monthly_breakdown.buy_heloc;
+                        if heloc > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"HELOC Payment"</span>
+                                    <span class="value">{format!("${:.0}", heloc)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let capex = result.get().monthly_breakdown.buy_capex;
+                        if capex > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Major Repairs"</span>
+                                    <span class="value">{format!("${:.0}", capex)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let income = result.get().monthly_breakdown.buy_rental_income;
+                        if income > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Rental Income"</span>
+                                    <span class="value positive">{format!("-${:.0}", income)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
+                    {move || {
+                        let rent = result.get().monthly_breakdown.buy_rent;
+                        if rent > 0.0 {
+                            view! {
+                                <div class="breakdown-item">
+                                    <span class="label">"Rent After Moving Out"</span>
+                                    <span class="value">{format!("${:.0}", rent)}</span>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }
+                    }}
 
                     <div class="breakdown-item total">
                         <span class="label">"Total Monthly"</span>
@@ -978,5 +3458,131 @@ fn BreakdownSection(result: Memo<CalculationResult>) -> impl IntoView {
                 </div>
             </div>
         </div>
+
+        <ScheduleTable result=result />
+    }
+}
+
+/// Months shown per page of the schedule (one year)
+const SCHEDULE_PAGE_SIZE: usize = 12;
+
+#[component]
+fn ScheduleTable(result: Memo<CalculationResult>) -> impl IntoView {
+    let (expanded, set_expanded) = create_signal(false);
+    let (page, set_page) = create_signal(0usize);
+
+    let page_count = move || result.with(|r| r.monthly_schedule.len().div_ceil(SCHEDULE_PAGE_SIZE).max(1));
+
+    // Stay on a valid page when the horizon shrinks
+    create_effect(move |_| {
+        let last = page_count() - 1;
+        if page.get_untracked() > last {
+            set_page.set(last);
+        }
+    });
+
+    let rows = move || {
+        result.with(|r| {
+            r.monthly_schedule
+                .iter()
+                .skip(page.get() * SCHEDULE_PAGE_SIZE)
+                .take(SCHEDULE_PAGE_SIZE)
+                .map(|row| {
+                    view! {
+                        <tr>
+                            <td>{row.month}</td>
+                            <td>{format_currency_full(row.payment)}</td>
+                            <td>{format_currency_full(row.interest)}</td>
+                            <td>{format_currency_full(row.principal)}</td>
+                            <td>{format_currency_full(row.balance)}</td>
+                            <td>{format_currency_full(row.pmi)}</td>
+                            <td>{format_currency_full(row.property_tax)}</td>
+                            <td>{format_currency_full(row.insurance)}</td>
+                            <td>{format_currency_full(row.hoa)}</td>
+                            <td>{format_currency_full(row.maintenance)}</td>
+                            <td>
+                                {if row.capex > 0.0 {
+                                    format!("{} ({})", format_currency_full(row.capex), row.capex_items)
+                                } else {
+                                    format_currency_full(row.capex)
+                                }}
+                            </td>
+                            <td>{format_currency_full(row.rental_income)}</td>
+                            <td>{format_currency_full(row.owner_rent)}</td>
+                            <td>{format_currency_full(row.rent)}</td>
+                            <td>{format_currency_full(row.buyer_contribution)}</td>
+                            <td>{format_currency_full(row.buyer_investment_balance)}</td>
+                            <td>{format_currency_full(row.renter_contribution)}</td>
+                            <td>{format_currency_full(row.renter_investment_balance)}</td>
+                            <td>{format_currency_full(row.home_value)}</td>
+                            <td>{format_currency_full(row.equity)}</td>
+                            <td class="buy">{format_currency_full(row.buy_net_worth)}</td>
+                            <td class="rent">{format_currency_full(row.rent_net_worth)}</td>
+                        </tr>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="breakdown-section">
+            <div class="section-title schedule-header">
+                <span>"Month-by-Month Schedule"</span>
+                <button class="list-button" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                    {move || if expanded.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            <Show when=move || expanded.get()>
+                <div class="schedule-pager">
+                    <button
+                        class="list-button"
+                        disabled=move || page.get() == 0
+                        on:click=move |_| set_page.update(|p| *p = p.saturating_sub(1))
+                    >
+                        "← Previous"
+                    </button>
+                    <span>{move || format!("Year {} of {}", page.get() + 1, page_count())}</span>
+                    <button
+                        class="list-button"
+                        disabled=move || page.get() + 1 >= page_count()
+                        on:click=move |_| set_page.update(|p| *p += 1)
+                    >
+                        "Next →"
+                    </button>
+                </div>
+                <div class="schedule-table-wrapper">
+                    <table class="schedule-table">
+                        <thead>
+                            <tr>
+                                <th>"Month"</th>
+                                <th>"Payment"</th>
+                                <th>"Interest"</th>
+                                <th>"Principal"</th>
+                                <th>"Balance"</th>
+                                <th>"PMI"</th>
+                                <th>"Property Tax"</th>
+                                <th>"Insurance"</th>
+                                <th>"HOA"</th>
+                                <th>"Maintenance"</th>
+                                <th>"Major Repairs"</th>
+                                <th>"Rental Income"</th>
+                                <th>"Owner's Rent"</th>
+                                <th>"Rent"</th>
+                                <th>"Buyer Invests"</th>
+                                <th>"Buyer Portfolio"</th>
+                                <th>"Renter Invests"</th>
+                                <th>"Renter Portfolio"</th>
+                                <th>"Home Value"</th>
+                                <th>"Equity"</th>
+                                <th>"Buy Net Worth"</th>
+                                <th>"Rent Net Worth"</th>
+                            </tr>
+                        </thead>
+                        <tbody>{rows}</tbody>
+                    </table>
+                </div>
+            </Show>
+        </div>
     }
 }
@@ -0,0 +1,73 @@
+use crate::calculations::{calculate_with_path, Detail, Inputs, MarketPath};
+use crate::historical_data::{HistoricalYear, HISTORY};
+use crate::monte_carlo::PercentileBand;
+
+/// Outcome of buying vs renting starting in one historical year
+#[derive(Clone, Debug, PartialEq)]
+pub struct BacktestRun {
+    pub start_year: u32,
+    pub mortgage_rate: f64,
+    pub buy_net_worth: f64,
+    pub rent_net_worth: f64,
+    pub difference: f64, // Positive = buying wins
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BacktestResult {
+    pub runs: Vec<BacktestRun>,     // In start-year order
+    pub difference: PercentileBand,
+    pub buy_win_share: f64,         // 0.0 to 1.0
+    pub best: Option<BacktestRun>,  // Best start year for buying
+    pub worst: Option<BacktestRun>, // Worst start year for buying
+}
+
+/// Replay the inputs with realized rates from a stretch of history
+fn run_window(inputs: &Inputs, window: &[HistoricalYear]) -> BacktestRun {
+    let start = window[0];
+
+    // Real-dollar reporting uses a constant rate, so use the window's
+    // compound average; the ending values deflate exactly
+    let growth: f64 = window.iter().map(|y| 1.0 + y.inflation / 100.0).product();
+    let average_inflation = (growth.powf(1.0 / window.len() as f64) - 1.0) * 100.0;
+
+    let historical_inputs = Inputs {
+        mortgage_rate: start.mortgage_rate,
+        inflation_rate: average_inflation,
+        ..inputs.clone()
+    };
+    let path = MarketPath {
+        home_appreciation: window.iter().map(|y| y.home_price_change).collect(),
+        rent_increase: window.iter().map(|y| y.rent_change).collect(),
+        investment_return: window.iter().map(|y| y.equity_return).collect(),
+        investment_anchor: MarketPath::neutral_investment_return(inputs),
+    };
+
+    let result = calculate_with_path(&historical_inputs, &path, Detail::Summary);
+    BacktestRun {
+        start_year: start.year,
+        mortgage_rate: start.mortgage_rate,
+        buy_net_worth: result.buy_breakdown.net_worth,
+        rent_net_worth: result.rent_breakdown.net_worth,
+        difference: result.difference,
+    }
+}
+
+/// Run the comparison from every start year with a full horizon of history
+pub fn run_backtest(inputs: &Inputs) -> BacktestResult {
+    let years = (inputs.time_horizon_years as usize).max(1);
+    if years > HISTORY.len() {
+        return BacktestResult::default();
+    }
+
+    let runs: Vec<BacktestRun> = HISTORY.windows(years).map(|w| run_window(inputs, w)).collect();
+    let mut differences: Vec<f64> = runs.iter().map(|r| r.difference).collect();
+    let buy_wins = runs.iter().filter(|r| r.difference > 0.0).count();
+
+    BacktestResult {
+        difference: PercentileBand::from_values(&mut differences),
+        buy_win_share: buy_wins as f64 / runs.len() as f64,
+        best: runs.iter().max_by(|a, b| a.difference.total_cmp(&b.difference)).cloned(),
+        worst: runs.iter().min_by(|a, b| a.difference.total_cmp(&b.difference)).cloned(),
+        runs,
+    }
+}
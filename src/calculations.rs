@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 /// All the financial calculations for buy vs rent comparison
 ///
 /// Fair comparison assuming same income/budget:
@@ -11,17 +13,85 @@ pub struct Inputs {
     pub down_payment_percent: f64,
     pub mortgage_rate: f64,
     pub loan_term_years: u32,
+    pub mortgage_type: MortgageType,
+    pub arm_index_rate: f64,   // Index (e.g. SOFR) value today
+    pub arm_index_change: f64, // Index drift in percentage points per year
+    pub arm_margin: f64,
+    pub arm_initial_cap: f64,
+    pub arm_periodic_cap: f64,
+    pub arm_lifetime_cap: f64,
+    pub discount_points: f64,          // Each point costs 1% of the loan at closing
+    pub rate_reduction_per_point: f64, // Percentage points off the rate per point
+    pub buy_down: BuyDown,
+    pub buy_down_seller_paid: bool,    // Otherwise the buyer funds the subsidy at closing
+    pub extra_monthly_principal: f64,
+    pub extra_annual_principal: f64, // Lump sum paid at the end of each loan year
+    pub extra_payments: Vec<ExtraPayment>,
+    pub refinances: Vec<RefinanceEvent>,
+    pub improvements: Vec<ImprovementProject>,
+    pub heloc_rate: f64,
+    pub heloc_term_years: u32, // Each draw re-amortizes the line over this many years
+    pub pmi_rate: f64,                        // Annual PMI premium as % of the original loan
+    pub pmi_request_cancellation: bool,       // Borrower asks to drop PMI at 80% of original price
+    pub pmi_current_value_cancellation: bool, // Borrower gets PMI dropped based on a new appraisal
     pub property_tax_rate: f64,
+    pub assessment_capped: bool,   // Tax an assessed value instead of the market value
+    pub assessment_cap: f64,       // Most the assessed value can rise per year (Prop 13, Save Our Homes)
+    pub homestead_exemption: f64,  // Taken off the taxable value while the owner lives there
+    pub millage_rate: f64,         // Tax per $1,000 of capped assessed value; `property_tax_rate` applies otherwise
     pub home_insurance: f64,
+    pub home_insurance_increase: f64, // Yearly premium growth, also for landlord insurance
     pub hoa_monthly: f64,
+    pub hoa_increase: f64,
     pub maintenance_percent: f64,
+    pub maintenance_floor: f64,       // Least spent on upkeep per year, in today's dollars
+    pub capex_items: Vec<CapexItem>,
+    pub capex_replaces_maintenance: bool, // Only the floor is spent on routine upkeep
     pub home_appreciation: f64,
+    pub rental_income_monthly: f64,     // Gross rent from a room, unit or ADU
+    pub rental_income_growth: f64,
+    pub rental_vacancy_percent: f64,
+    pub rental_management_percent: f64, // Share of collected rent
+    pub rental_income_taxed: bool,      // Report it, with depreciation, instead of ignoring tax
+    pub rental_share_percent: f64,      // Share of the property that's rented out
     pub closing_cost_percent: f64,
     pub selling_cost_percent: f64,
+    pub exit_strategy: ExitStrategy,
+    pub move_out_year: u32,               // Owner moves out and rents the home from the end of this year
+    pub landlord_rent: f64,               // Market rent for the whole home today
+    pub landlord_vacancy_percent: f64,
+    pub landlord_management_percent: f64, // Share of collected rent
+    pub landlord_insurance: f64,          // Annual premium, replaces homeowner's insurance
     pub monthly_rent: f64,
-    pub rent_increase_rate: f64,
+    pub rent_increase_rate: f64,   // Market rent growth
+    pub renewal_increase_cap: f64, // Most the landlord raises rent at renewal
     pub renters_insurance: f64,
-    pub investment_return: f64,
+    pub renters_insurance_increase: f64,
+    pub security_deposit_months: f64,
+    pub application_fee: f64,      // Per lease
+    pub broker_fee_percent: f64,   // Per lease, as % of the first year's rent
+    pub move_every_years: u32,     // 0 = never moves
+    pub moving_cost: f64,          // In today's dollars
+    pub buyer_investment_return: f64,
+    pub buyer_expense_ratio: f64,  // Annual fund expenses, % of the balance
+    pub buyer_advisory_fee: f64,   // Annual advisory fee, % of the balance
+    pub buyer_equity_share: f64,   // % of the portfolio in stocks, which carry market swings
+    pub renter_investment_return: f64,
+    pub renter_expense_ratio: f64,
+    pub renter_advisory_fee: f64,
+    pub renter_equity_share: f64,
+    pub account_type: AccountType,
+    pub dividend_yield: f64,
+    pub marginal_tax_rate: f64,
+    pub filing_status: FilingStatus,
+    pub standard_deduction: f64,
+    pub salt_cap: f64,
+    pub state_income_tax: f64, // Yearly state and local income tax, in today's dollars; shares the SALT cap
+    pub capital_gains_rate: f64,
+    pub home_sale_exclusion: f64,
+    pub exclusion_min_years: u32, // Years of ownership needed to claim the exclusion
+    pub inflation_rate: f64,
+    pub real_dollars: bool,       // Report every amount in today's dollars
     pub time_horizon_years: u32,
 }
 
@@ -32,27 +102,477 @@ impl Default for Inputs {
             down_payment_percent: 20.0,
             mortgage_rate: 6.5,
             loan_term_years: 30,
+            mortgage_type: MortgageType::Fixed,
+            arm_index_rate: 4.0,
+            arm_index_change: 0.0,
+            arm_margin: 2.75,
+            arm_initial_cap: 2.0,
+            arm_periodic_cap: 2.0,
+            arm_lifetime_cap: 5.0,
+            discount_points: 0.0,
+            rate_reduction_per_point: 0.25,
+            buy_down: BuyDown::None,
+            buy_down_seller_paid: true,
+            extra_monthly_principal: 0.0,
+            extra_annual_principal: 0.0,
+            extra_payments: Vec::new(),
+            refinances: Vec::new(),
+            improvements: Vec::new(),
+            heloc_rate: 8.5,
+            heloc_term_years: 10,
+            pmi_rate: 0.5,
+            pmi_request_cancellation: false,
+            pmi_current_value_cancellation: false,
             property_tax_rate: 1.2,
+            assessment_capped: false,
+            assessment_cap: 2.0,
+            homestead_exemption: 0.0,
+            millage_rate: 12.0,
             home_insurance: 1_500.0,
+            home_insurance_increase: 3.0,
             hoa_monthly: 0.0,
+            hoa_increase: 3.0,
             maintenance_percent: 1.0,
+            maintenance_floor: 0.0,
+            capex_items: Vec::new(),
+            capex_replaces_maintenance: false,
             home_appreciation: 3.0,
+            rental_income_monthly: 0.0,
+            rental_income_growth: 3.0,
+            rental_vacancy_percent: 5.0,
+            rental_management_percent: 0.0,
+            rental_income_taxed: false,
+            rental_share_percent: 25.0,
             closing_cost_percent: 3.0,
             selling_cost_percent: 6.0,
+            exit_strategy: ExitStrategy::Sell,
+            move_out_year: 5,
+            landlord_rent: 2_500.0,
+            landlord_vacancy_percent: 8.0,
+            landlord_management_percent: 8.0,
+            landlord_insurance: 1_800.0,
             monthly_rent: 2_000.0,
             rent_increase_rate: 3.0,
+            renewal_increase_cap: 5.0,
             renters_insurance: 200.0,
-            investment_return: 7.0,
+            renters_insurance_increase: 3.0,
+            security_deposit_months: 1.0,
+            application_fee: 50.0,
+            broker_fee_percent: 0.0,
+            move_every_years: 0,
+            moving_cost: 2_000.0,
+            buyer_investment_return: 7.0,
+            buyer_expense_ratio: 0.05,
+            buyer_advisory_fee: 0.0,
+            buyer_equity_share: 100.0,
+            renter_investment_return: 7.0,
+            renter_expense_ratio: 0.05,
+            renter_advisory_fee: 0.0,
+            renter_equity_share: 100.0,
+            account_type: AccountType::Taxable,
+            dividend_yield: 1.5,
+            marginal_tax_rate: 24.0,
+            filing_status: FilingStatus::Single,
+            standard_deduction: FilingStatus::Single.standard_deduction(),
+            salt_cap: FilingStatus::Single.salt_cap(),
+            state_income_tax: 0.0,
+            capital_gains_rate: 15.0,
+            home_sale_exclusion: FilingStatus::Single.home_sale_exclusion(),
+            exclusion_min_years: 2,
+            inflation_rate: 2.5,
+            real_dollars: false,
             time_horizon_years: 10,
         }
     }
 }
 
+/// Tax filing status, which sets the default deduction limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilingStatus {
+    Single,
+    MarriedFilingJointly,
+    MarriedFilingSeparately,
+    HeadOfHousehold,
+}
+
+impl FilingStatus {
+    pub const ALL: [FilingStatus; 4] = [
+        FilingStatus::Single,
+        FilingStatus::MarriedFilingJointly,
+        FilingStatus::MarriedFilingSeparately,
+        FilingStatus::HeadOfHousehold,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FilingStatus::Single => "Single",
+            FilingStatus::MarriedFilingJointly => "Married filing jointly",
+            FilingStatus::MarriedFilingSeparately => "Married filing separately",
+            FilingStatus::HeadOfHousehold => "Head of household",
+        }
+    }
+
+    /// 2026 federal standard deduction
+    pub fn standard_deduction(self) -> f64 {
+        match self {
+            FilingStatus::Single | FilingStatus::MarriedFilingSeparately => 16_100.0,
+            FilingStatus::MarriedFilingJointly => 32_200.0,
+            FilingStatus::HeadOfHousehold => 24_150.0,
+        }
+    }
+
+    /// 2026 cap on state and local tax (SALT) deductions. It's held flat over the
+    /// whole horizon: the model has no calendar year, so the scheduled return to
+    /// $10,000 after 2029 isn't applied
+    pub fn salt_cap(self) -> f64 {
+        match self {
+            FilingStatus::MarriedFilingSeparately => 20_200.0,
+            _ => 40_400.0,
+        }
+    }
+
+    /// Gain excluded from tax when selling a primary residence
+    pub fn home_sale_exclusion(self) -> f64 {
+        match self {
+            FilingStatus::MarriedFilingJointly => 500_000.0,
+            _ => 250_000.0,
+        }
+    }
+
+    /// Mortgage debt on which interest is deductible
+    pub fn mortgage_interest_debt_limit(self) -> f64 {
+        match self {
+            FilingStatus::MarriedFilingSeparately => 375_000.0,
+            _ => 750_000.0,
+        }
+    }
+}
+
+/// Fixed-rate mortgage or one of the common adjustable-rate (ARM) products
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MortgageType {
+    Fixed,
+    Arm5_1,
+    Arm7_1,
+    Arm5_6,
+    Arm7_6,
+    Arm10_6,
+}
+
+impl MortgageType {
+    pub const ALL: [MortgageType; 6] = [
+        MortgageType::Fixed,
+        MortgageType::Arm5_1,
+        MortgageType::Arm7_1,
+        MortgageType::Arm5_6,
+        MortgageType::Arm7_6,
+        MortgageType::Arm10_6,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MortgageType::Fixed => "Fixed rate",
+            MortgageType::Arm5_1 => "5/1 ARM",
+            MortgageType::Arm7_1 => "7/1 ARM",
+            MortgageType::Arm5_6 => "5/6 ARM",
+            MortgageType::Arm7_6 => "7/6 ARM",
+            MortgageType::Arm10_6 => "10/6 ARM",
+        }
+    }
+
+    pub fn is_arm(self) -> bool {
+        self != MortgageType::Fixed
+    }
+
+    /// Months before the first rate reset
+    pub fn initial_fixed_months(self) -> u32 {
+        match self {
+            MortgageType::Fixed => u32::MAX,
+            MortgageType::Arm5_1 | MortgageType::Arm5_6 => 60,
+            MortgageType::Arm7_1 | MortgageType::Arm7_6 => 84,
+            MortgageType::Arm10_6 => 120,
+        }
+    }
+
+    /// Months between resets after the first one
+    pub fn adjustment_months(self) -> u32 {
+        match self {
+            MortgageType::Fixed => u32::MAX,
+            MortgageType::Arm5_1 | MortgageType::Arm7_1 => 12,
+            MortgageType::Arm5_6 | MortgageType::Arm7_6 | MortgageType::Arm10_6 => 6,
+        }
+    }
+
+    /// Typical (initial, periodic, lifetime) rate caps for the product
+    pub fn default_caps(self) -> (f64, f64, f64) {
+        match self {
+            MortgageType::Fixed | MortgageType::Arm5_1 => (2.0, 2.0, 5.0),
+            MortgageType::Arm5_6 => (2.0, 1.0, 5.0),
+            MortgageType::Arm7_1 => (5.0, 2.0, 5.0),
+            MortgageType::Arm7_6 | MortgageType::Arm10_6 => (5.0, 1.0, 5.0),
+        }
+    }
+}
+
+/// Temporary buy-down that lowers the payment for the first years of the loan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuyDown {
+    None,
+    TwoOne,
+    ThreeTwoOne,
+}
+
+impl BuyDown {
+    pub const ALL: [BuyDown; 3] = [BuyDown::None, BuyDown::TwoOne, BuyDown::ThreeTwoOne];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BuyDown::None => "None",
+            BuyDown::TwoOne => "2-1 buy-down",
+            BuyDown::ThreeTwoOne => "3-2-1 buy-down",
+        }
+    }
+
+    /// Rate reduction for each subsidized year, in percentage points
+    pub fn reductions(self) -> &'static [f64] {
+        match self {
+            BuyDown::None => &[],
+            BuyDown::TwoOne => &[2.0, 1.0],
+            BuyDown::ThreeTwoOne => &[3.0, 2.0, 1.0],
+        }
+    }
+}
+
+/// What happens to the home at the end of the horizon
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitStrategy {
+    Sell,           // Live there throughout and sell at the end
+    RentOutAndHold, // Move out, rent it out and keep it; valued as equity without a sale
+    RentOutAndSell, // Move out, rent it out and sell at the end
+}
+
+impl ExitStrategy {
+    pub const ALL: [ExitStrategy; 3] = [ExitStrategy::Sell, ExitStrategy::RentOutAndHold, ExitStrategy::RentOutAndSell];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExitStrategy::Sell => "Live there and sell",
+            ExitStrategy::RentOutAndHold => "Become a landlord and keep it",
+            ExitStrategy::RentOutAndSell => "Become a landlord, then sell",
+        }
+    }
+
+    pub fn keeps_as_rental(self) -> bool {
+        self != ExitStrategy::Sell
+    }
+}
+
+/// A one-off extra principal payment made in a specific month of the loan
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtraPayment {
+    pub month: u32,
+    pub amount: f64,
+}
+
+/// Refinancing the remaining balance into a new fixed-rate loan
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefinanceEvent {
+    pub month: u32,
+    pub rate: f64,
+    pub term_years: u32,
+    pub closing_costs: f64,
+    pub cash_out: f64, // Extra borrowed on top of the remaining balance
+}
+
+/// Outcome of one refinance
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RefinanceSummary {
+    pub month: u32,
+    pub closing_costs: f64,
+    pub cash_out: f64,
+    pub new_payment: f64,
+    pub monthly_savings: f64,          // Old payment minus new payment on the same balance
+    pub break_even_month: Option<u32>, // None if the refinance never pays back its costs
+}
+
+/// How a renovation is paid for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FundingSource {
+    Cash,
+    Heloc,    // Drawn on a home equity line of credit
+    Mortgage, // Added to the mortgage balance and re-amortized
+}
+
+impl FundingSource {
+    pub const ALL: [FundingSource; 3] = [FundingSource::Cash, FundingSource::Heloc, FundingSource::Mortgage];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FundingSource::Cash => "Cash",
+            FundingSource::Heloc => "HELOC",
+            FundingSource::Mortgage => "Mortgage",
+        }
+    }
+}
+
+/// A renovation or addition finished in a specific month
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImprovementProject {
+    pub name: String,
+    pub month: u32,
+    pub cost: f64,
+    pub funding: FundingSource,
+    pub value_uplift_percent: f64, // Rise in the home's value once it's done
+}
+
+/// Outcome of one improvement project
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImprovementSummary {
+    pub name: String,
+    pub month: u32,
+    pub cost: f64,
+    pub funding: FundingSource,
+    pub value_added: f64,
+}
+
+/// A major component (roof, HVAC...) that's replaced at the end of its life
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapexItem {
+    pub name: String,
+    pub cost: f64, // Replacement cost in today's dollars
+    pub useful_life_years: u32,
+    pub age_years: u32, // Age when the home is bought
+}
+
+impl CapexItem {
+    fn new(name: &str, cost: f64, useful_life_years: u32, age_years: u32) -> Self {
+        Self { name: name.to_string(), cost, useful_life_years, age_years }
+    }
+
+    /// Typical big-ticket items for a single-family home
+    pub fn typical() -> Vec<CapexItem> {
+        vec![
+            CapexItem::new("Roof", 15_000.0, 25, 10),
+            CapexItem::new("HVAC", 10_000.0, 15, 5),
+            CapexItem::new("Water heater", 2_000.0, 12, 4),
+            CapexItem::new("Appliances", 6_000.0, 12, 3),
+        ]
+    }
+
+    /// Whether a replacement falls due in `month`; an item already at the end
+    /// of its life is replaced in the first month
+    pub fn is_due(&self, month: u32) -> bool {
+        let life_months = self.useful_life_years.max(1).saturating_mul(12);
+        let first_due = life_months.saturating_sub(self.age_years.saturating_mul(12)).saturating_add(1);
+        month >= first_due && (month - first_due).is_multiple_of(life_months)
+    }
+}
+
+/// One replacement from the capex schedule
 #[derive(Clone, Debug, Default, PartialEq)]
+pub struct CapexReplacement {
+    pub name: String,
+    pub month: u32,
+    pub cost: f64,
+}
+
+/// How the buyer's and renter's investment portfolios are taxed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountType {
+    Taxable, // Dividends taxed yearly, gains taxed at liquidation
+    // Funded with after-tax money, like a non-deductible IRA or an annuity: growth is
+    // deferred and the gains, not the contributions, are ordinary income at liquidation.
+    // Pre-tax accounts such as a 401(k) aren't modeled, since a down payment can't come from one.
+    NonDeductible,
+    TaxFree, // Never taxed
+}
+
+impl AccountType {
+    pub const ALL: [AccountType; 3] = [AccountType::Taxable, AccountType::NonDeductible, AccountType::TaxFree];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AccountType::Taxable => "Taxable brokerage",
+            AccountType::NonDeductible => "Tax-deferred, non-deductible",
+            AccountType::TaxFree => "Tax-free",
+        }
+    }
+}
+
+/// PMI is required while the loan is above this share of the original price
+const PMI_REQUIRED_LTV: f64 = 80.0;
+/// Lenders must drop PMI automatically once the original schedule reaches this LTV
+const PMI_AUTOMATIC_CANCEL_LTV: f64 = 78.0;
+/// A borrower can ask for PMI to be dropped at this LTV of the original price
+const PMI_REQUEST_CANCEL_LTV: f64 = 80.0;
+
+/// Share of the purchase that's the building rather than land, and so depreciable
+const DEPRECIABLE_BUILDING_SHARE: f64 = 0.8;
+/// Recovery period for residential rental property
+const RESIDENTIAL_DEPRECIATION_YEARS: f64 = 27.5;
+/// Highest rate on depreciation recaptured at sale
+const DEPRECIATION_RECAPTURE_MAX_RATE: f64 = 25.0;
+/// The home sale exclusion survives renting the home out for at most this long
+/// (the owner must have lived there two of the last five years)
+const EXCLUSION_MAX_MONTHS_RENTED: u32 = 36;
+
+/// Net worth of each side at the end of a year, with what it's made of.
+/// Buy net worth = equity - selling costs - home sale tax + buyer portfolio - its tax;
+/// rent net worth = renter portfolio - its tax.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
 pub struct YearlySnapshot {
     pub year: u32,
     pub buy_net_worth: f64,
     pub rent_net_worth: f64,
+    // Buy side
+    pub home_value: f64,
+    pub mortgage_balance: f64,          // Including any HELOC
+    pub equity: f64,
+    pub selling_costs: f64,             // If the home were sold now
+    pub home_sale_tax: f64,             // If the home were sold now
+    pub buyer_portfolio: f64,
+    pub buyer_portfolio_tax: f64,       // If the portfolio were cashed out now
+    pub buy_cumulative_spend: f64,      // Down payment, closing and every housing cost so far
+    pub buy_monthly_cost: f64,          // Cost of owning in the last month of the year
+    // Rent side
+    pub renter_portfolio: f64,
+    pub renter_portfolio_tax: f64,      // If the portfolio were cashed out now
+    pub rent_cumulative_spend: f64,     // Rent and renter's insurance so far
+    pub current_rent: f64,              // Rent in the last month of the year
+}
+
+/// One month of the amortization and cash-flow schedule
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MonthlyRow {
+    pub month: u32,
+    // Mortgage
+    pub payment: f64,   // Buyer's share of the scheduled payment plus any extra principal
+    pub interest: f64,
+    pub principal: f64,
+    pub balance: f64,
+    // Other ownership costs (rental income offsets them)
+    pub pmi: f64,
+    pub property_tax: f64,
+    pub insurance: f64,
+    pub hoa: f64,
+    pub maintenance: f64,
+    pub capex: f64,
+    pub capex_items: String, // Names of the items replaced this month
+    pub rental_income: f64,
+    pub owner_rent: f64, // Once the owner has moved out
+    // Renting
+    pub rent: f64,
+    // Investments (whoever spends less invests the difference)
+    pub buyer_contribution: f64,
+    pub buyer_investment_balance: f64,
+    pub renter_contribution: f64,
+    pub renter_investment_balance: f64,
+    // Home
+    pub home_value: f64,
+    pub equity: f64,
+    // Net worth if sold and cashed out this month
+    pub buy_net_worth: f64,
+    pub rent_net_worth: f64,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -62,28 +582,62 @@ pub struct BuyBreakdown {
     pub total_mortgage_payments: f64,
     pub total_interest_paid: f64,
     pub total_principal_paid: f64,
+    pub extra_principal_paid: f64,       // Prepayments beyond the scheduled payment
+    pub interest_saved: f64,             // Lifetime interest avoided by prepaying
+    pub payoff_month: u32,               // Month the loan is paid off, with prepayments
+    pub points_cost: f64,                // Discount points paid at closing
+    pub points_break_even_month: Option<u32>, // When the lower payment has repaid the points
+    pub buy_down_subsidy: f64,           // Payments covered by a temporary buy-down
+    pub rental_income: f64,              // Rent collected after vacancy and management
+    pub rental_income_tax: f64,
+    pub rent_after_move_out: f64,        // Rent the owner pays once the home is a rental
+    pub kept_as_rental: bool,            // Valued at its equity rather than sold
+    pub depreciation_recapture_tax: f64, // Part of the sale tax due to depreciation taken
+    pub refinances: Vec<RefinanceSummary>,
+    pub total_pmi: f64,
     pub total_property_tax: f64,
+    pub final_assessed_value: f64,       // Value property tax is charged on at the end
     pub total_insurance: f64,
     pub total_hoa: f64,
     pub total_maintenance: f64,
+    pub capex: Vec<CapexReplacement>,
+    pub total_capex: f64,
+    pub improvements: Vec<ImprovementSummary>,
+    pub total_improvements: f64,
+    pub heloc_payments: f64,
+    pub heloc_interest: f64,
     pub selling_costs: f64,
+    pub home_cost_basis: f64,            // Purchase price + closing costs + capital improvements
+    pub home_sale_tax: f64,              // Capital gains tax on the sale after the exclusion
     pub final_home_value: f64,
     pub remaining_mortgage: f64,
+    pub remaining_heloc: f64,
     // Buyer's investments (when buying is cheaper than renting)
     pub monthly_savings_invested: f64,
     pub investment_returns: f64,
     pub investment_balance: f64,
+    pub investment_tax_drag: f64,        // Tax paid on dividends along the way
+    pub investment_fees: f64,            // Expense ratio and advisory fees
+    pub investment_liquidation_tax: f64, // Tax due when the portfolio is cashed out
+    pub tax_savings: f64, // Income tax saved by deductions renting wouldn't have had
     pub net_worth: f64,
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct RentBreakdown {
-    pub initial_investment: f64,         // Down payment + closing costs invested
+    pub initial_investment: f64,         // Down payment + closing costs, less lease costs, invested
     pub total_rent_paid: f64,
     pub total_renters_insurance: f64,
+    pub security_deposit: f64,           // Held by the landlord and returned at the end
+    pub rental_fees: f64,                // Application and broker fees on every lease
+    pub moving_costs: f64,
+    pub moves: u32,
     pub monthly_cost_savings: f64,       // Total saved because rent < buy (can be negative)
     pub investment_returns: f64,         // Market gains on all invested money
     pub final_investment_value: f64,     // Total portfolio value
+    pub investment_tax_drag: f64,        // Tax paid on dividends along the way
+    pub investment_fees: f64,            // Expense ratio and advisory fees
+    pub investment_liquidation_tax: f64, // Tax due when the portfolio is cashed out
     pub net_worth: f64,
 }
 
@@ -100,10 +654,17 @@ pub struct MonthlyCostComparison {
 pub struct MonthlyBreakdown {
     // Buy costs (monthly averages)
     pub buy_mortgage: f64,
+    pub buy_mortgage_initial: f64,       // First payment, before any ARM reset
+    pub buy_mortgage_peak: f64,          // Highest payment over the horizon
+    pub buy_pmi: f64,
     pub buy_property_tax: f64,
     pub buy_insurance: f64,
     pub buy_hoa: f64,
     pub buy_maintenance: f64,
+    pub buy_capex: f64,
+    pub buy_heloc: f64,
+    pub buy_rental_income: f64,          // Offsets the costs above
+    pub buy_rent: f64,                   // Owner's own rent after moving out
     pub buy_total: f64,
     // Rent costs (monthly averages)
     pub rent_payment: f64,
@@ -119,18 +680,368 @@ pub struct CalculationResult {
     pub monthly_breakdown: MonthlyBreakdown,
     pub difference: f64, // Positive means buying is better
     pub yearly_snapshots: Vec<YearlySnapshot>,
+    pub monthly_schedule: Vec<MonthlyRow>,
+    pub break_even: Option<BreakEven>, // None if buying never pulls ahead for good
+}
+
+/// When buying moves ahead of renting and stays ahead
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BreakEven {
+    pub month: u32, // Months since purchase
+    pub year: u32,  // Year of ownership the month falls in
 }
 
 /// Calculate monthly mortgage payment using standard amortization formula
 pub fn calculate_monthly_payment(principal: f64, annual_rate: f64, years: u32) -> f64 {
+    amortized_payment(principal, annual_rate, years * 12)
+}
+
+/// Monthly payment that pays off `principal` over `months` payments
+fn amortized_payment(principal: f64, annual_rate: f64, months: u32) -> f64 {
+    if months == 0 {
+        return principal;
+    }
     if annual_rate == 0.0 {
-        return principal / (years as f64 * 12.0);
+        return principal / months as f64;
     }
     let monthly_rate = annual_rate / 100.0 / 12.0;
-    let n = years as f64 * 12.0;
+    let n = months as f64;
     principal * (monthly_rate * (1.0 + monthly_rate).powf(n)) / ((1.0 + monthly_rate).powf(n) - 1.0)
 }
 
+/// One month of mortgage activity
+struct MortgagePayment {
+    payment: f64,
+    subsidy: f64, // Part of the payment covered by a temporary buy-down
+    interest: f64,
+    extra_principal: f64,
+    refinance: Option<RefinanceSummary>,
+}
+
+/// Month-by-month mortgage state, so the payment can change when an ARM
+/// resets or the loan is refinanced
+struct Mortgage {
+    balance: f64,
+    rate: f64,
+    payment: f64,
+    months_paid: u32,
+    term_months: u32,
+    resets: u32,
+    adjustable: bool,
+    buy_down_subsidies: Vec<f64>, // Monthly subsidy for each bought-down year
+}
+
+impl Mortgage {
+    fn new(inputs: &Inputs, amount: f64) -> Self {
+        let term_months = inputs.loan_term_years * 12;
+        let rate = note_rate(inputs);
+        let payment = amortized_payment(amount, rate, term_months);
+
+        // The loan amortizes at the note rate; the buy-down covers the gap to
+        // the payment at the reduced rate
+        let buy_down_subsidies = inputs
+            .buy_down
+            .reductions()
+            .iter()
+            .map(|r| payment - amortized_payment(amount, (rate - r).max(0.0), term_months))
+            .collect();
+
+        Self {
+            balance: amount,
+            rate,
+            payment,
+            months_paid: 0,
+            term_months,
+            resets: 0,
+            adjustable: inputs.mortgage_type.is_arm(),
+            buy_down_subsidies,
+        }
+    }
+
+    /// Add to the balance and re-amortize over what's left of the term
+    /// (at least a year, if the loan had already run its course)
+    fn borrow(&mut self, amount: f64) {
+        let remaining_months = self.term_months.saturating_sub(self.months_paid).max(12);
+        self.term_months = self.months_paid + remaining_months;
+        self.balance += amount;
+        self.payment = amortized_payment(self.balance, self.rate, remaining_months);
+    }
+
+    /// Total subsidy a buy-down escrow has to hold
+    fn buy_down_cost(&self) -> f64 {
+        self.buy_down_subsidies.iter().sum::<f64>() * 12.0
+    }
+
+    /// Replace the loan with a new fixed-rate one, starting at `month`
+    fn refinance(&mut self, event: &RefinanceEvent, month: u32) -> RefinanceSummary {
+        let new_term_months = event.term_years * 12;
+        let old_payment = if self.months_paid < self.term_months { self.payment } else { 0.0 };
+
+        // Savings compare like for like, leaving the cash-out out of the new payment
+        let like_for_like_payment = amortized_payment(self.balance, event.rate, new_term_months);
+        let monthly_savings = old_payment - like_for_like_payment;
+        let break_even_month = if monthly_savings > 0.0 {
+            Some(month + (event.closing_costs / monthly_savings).ceil() as u32)
+        } else {
+            None
+        };
+
+        self.balance += event.cash_out;
+        self.rate = event.rate;
+        self.term_months = self.months_paid + new_term_months;
+        self.payment = amortized_payment(self.balance, event.rate, new_term_months);
+        self.adjustable = false;
+        self.buy_down_subsidies.clear();
+
+        RefinanceSummary {
+            month,
+            closing_costs: event.closing_costs,
+            cash_out: event.cash_out,
+            new_payment: self.payment,
+            monthly_savings,
+            break_even_month,
+        }
+    }
+
+    /// ARM rate for the next period: index + margin, limited by the caps
+    fn reset_rate(&self, inputs: &Inputs) -> f64 {
+        let years_elapsed = self.months_paid as f64 / 12.0;
+        let index = (inputs.arm_index_rate + inputs.arm_index_change * years_elapsed).max(0.0);
+        let cap = if self.resets == 0 { inputs.arm_initial_cap } else { inputs.arm_periodic_cap };
+        (index + inputs.arm_margin)
+            .clamp(self.rate - cap, self.rate + cap)
+            .min(note_rate(inputs) + inputs.arm_lifetime_cap)
+            .max(inputs.arm_margin.min(note_rate(inputs)))
+    }
+
+    /// Apply any refinance or rate reset due in `month`, then make the month's
+    /// payment plus `extra` toward principal
+    fn pay_month(&mut self, inputs: &Inputs, month: u32, extra: f64) -> MortgagePayment {
+        let refinance = inputs
+            .refinances
+            .iter()
+            .find(|e| e.month == month && self.balance + e.cash_out > 0.0)
+            .map(|e| self.refinance(e, month));
+
+        if self.months_paid >= self.term_months || self.balance <= 0.0 {
+            return MortgagePayment { payment: 0.0, subsidy: 0.0, interest: 0.0, extra_principal: 0.0, refinance };
+        }
+
+        let fixed_months = inputs.mortgage_type.initial_fixed_months();
+        if self.adjustable
+            && self.months_paid >= fixed_months
+            && (self.months_paid - fixed_months).is_multiple_of(inputs.mortgage_type.adjustment_months())
+        {
+            self.rate = self.reset_rate(inputs);
+            self.resets += 1;
+            self.payment = amortized_payment(self.balance, self.rate, self.term_months - self.months_paid);
+        }
+
+        let interest = self.balance * self.rate / 100.0 / 12.0;
+        // The last payment clears whatever is left
+        let payment = if self.months_paid + 1 == self.term_months {
+            self.balance + interest
+        } else {
+            self.payment.min(self.balance + interest)
+        };
+        let subsidy = self
+            .buy_down_subsidies
+            .get((self.months_paid / 12) as usize)
+            .map_or(0.0, |s| s.min(payment));
+        self.balance -= payment - interest;
+        self.months_paid += 1;
+
+        let extra_principal = extra.min(self.balance).max(0.0);
+        self.balance -= extra_principal;
+
+        MortgagePayment { payment, subsidy, interest, extra_principal, refinance }
+    }
+}
+
+/// Tax on depreciation taken while renting part of the home, due when it's sold.
+/// Only the gain over the depreciation-adjusted basis is recaptured.
+fn depreciation_recapture_tax(
+    inputs: &Inputs,
+    accumulated_depreciation: f64,
+    sale_price: f64,
+    selling_costs: f64,
+    cost_basis: f64,
+) -> f64 {
+    let adjusted_basis = cost_basis - accumulated_depreciation;
+    let recaptured = accumulated_depreciation.min(sale_price - selling_costs - adjusted_basis).max(0.0);
+    recaptured * inputs.marginal_tax_rate.min(DEPRECIATION_RECAPTURE_MAX_RATE) / 100.0
+}
+
+/// Mortgage rate after any discount points
+fn note_rate(inputs: &Inputs) -> f64 {
+    (inputs.mortgage_rate - inputs.discount_points * inputs.rate_reduction_per_point).max(0.0)
+}
+
+/// Month when the payments saved by buying points have covered their cost. Both loans
+/// follow their own schedule through ARM resets and any buy-down; a refinance ends the
+/// comparison, so points that haven't paid back by then never do.
+fn points_break_even_month(inputs: &Inputs, loan_amount: f64, points_cost: f64) -> Option<u32> {
+    if points_cost <= 0.0 {
+        return None;
+    }
+    let with_points = Inputs { refinances: Vec::new(), ..inputs.clone() };
+    let without_points = Inputs { discount_points: 0.0, ..with_points.clone() };
+    let mut bought_down = Mortgage::new(&with_points, loan_amount);
+    let mut par = Mortgage::new(&without_points, loan_amount);
+
+    let first_refinance = inputs.refinances.iter().map(|e| e.month).min().unwrap_or(u32::MAX);
+    let last_month = bought_down.term_months.min(first_refinance.saturating_sub(1));
+    let mut saved = 0.0;
+    for month in 1..=last_month {
+        let with = bought_down.pay_month(&with_points, month, 0.0);
+        let without = par.pay_month(&without_points, month, 0.0);
+        saved += (without.payment - without.subsidy) - (with.payment - with.subsidy);
+        if saved >= points_cost {
+            return Some(month);
+        }
+    }
+    None
+}
+
+/// Extra principal the buyer has scheduled for a given month of the loan
+fn extra_principal_for_month(inputs: &Inputs, month: u32) -> f64 {
+    let annual = if month.is_multiple_of(12) { inputs.extra_annual_principal } else { 0.0 };
+    let one_off: f64 = inputs
+        .extra_payments
+        .iter()
+        .filter(|p| p.month == month)
+        .map(|p| p.amount)
+        .sum();
+    inputs.extra_monthly_principal + annual + one_off
+}
+
+/// Run the loan to payoff, returning lifetime interest and the payoff month
+fn simulate_loan_payoff(inputs: &Inputs, loan_amount: f64, with_extra_payments: bool) -> (f64, u32) {
+    let mut mortgage = Mortgage::new(inputs, loan_amount);
+    let mut total_interest = 0.0;
+    let mut month = 0;
+    // Renovations rolled into the mortgage add to the balance when they're done
+    let last_draw_month = inputs
+        .improvements
+        .iter()
+        .filter(|p| p.funding == FundingSource::Mortgage)
+        .map(|p| p.month)
+        .max()
+        .unwrap_or(0);
+    while month < last_draw_month || (mortgage.balance > 0.0 && mortgage.months_paid < mortgage.term_months) {
+        month += 1;
+        for project in inputs.improvements.iter().filter(|p| p.month == month && p.funding == FundingSource::Mortgage) {
+            mortgage.borrow(project.cost);
+        }
+        let extra = if with_extra_payments { extra_principal_for_month(inputs, month) } else { 0.0 };
+        total_interest += mortgage.pay_month(inputs, month, extra).interest * deflator(inputs, month - 1);
+    }
+    (total_interest, month)
+}
+
+/// Whether PMI can be dropped after `month` payments, given the actual balance and home value
+fn pmi_can_cancel(
+    inputs: &Inputs,
+    loan_amount: f64,
+    month: u32,
+    balance: f64,
+    current_home_value: f64,
+) -> bool {
+    let total_loan_months = inputs.loan_term_years * 12;
+
+    // Automatic termination follows the original amortization schedule, and PMI
+    // always ends at the loan's midpoint
+    let scheduled_balance = remaining_balance(loan_amount, note_rate(inputs), inputs.loan_term_years, month);
+    if scheduled_balance <= inputs.home_price * PMI_AUTOMATIC_CANCEL_LTV / 100.0 || month >= total_loan_months / 2 {
+        return true;
+    }
+
+    if inputs.pmi_request_cancellation && balance <= inputs.home_price * PMI_REQUEST_CANCEL_LTV / 100.0 {
+        return true;
+    }
+
+    // Lenders typically want 2 years of seasoning and 75% LTV on a new appraisal,
+    // relaxing to 80% after 5 years
+    if inputs.pmi_current_value_cancellation && month >= 24 {
+        let max_ltv = if month < 60 { 75.0 } else { 80.0 };
+        if balance <= current_home_value * max_ltv / 100.0 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Capital gains tax owed if the home sells for `sale_price` after `months_owned`,
+/// the last `months_rented` of them as a rental
+fn home_sale_capital_gains_tax(
+    inputs: &Inputs,
+    sale_price: f64,
+    selling_costs: f64,
+    cost_basis: f64,
+    months_owned: u32,
+    months_rented: u32,
+) -> f64 {
+    let gain = sale_price - selling_costs - cost_basis;
+    if gain <= 0.0 {
+        return 0.0;
+    }
+
+    let months_lived = months_owned - months_rented;
+    let exclusion = if months_lived >= inputs.exclusion_min_years * 12 && months_rented <= EXCLUSION_MAX_MONTHS_RENTED {
+        inputs.home_sale_exclusion
+    } else {
+        0.0
+    };
+    (gain - exclusion).max(0.0) * inputs.capital_gains_rate / 100.0
+}
+
+/// Dividends a portfolio pays this month and the tax due on them
+fn monthly_dividend_tax(inputs: &Inputs, balance: f64) -> (f64, f64) {
+    if inputs.account_type != AccountType::Taxable || balance <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let dividends = balance * inputs.dividend_yield / 100.0 / 12.0;
+    (dividends, dividends * inputs.capital_gains_rate / 100.0)
+}
+
+/// Fund expenses and advisory fees taken from a portfolio this month
+fn monthly_portfolio_fee(balance: f64, expense_ratio: f64, advisory_fee: f64) -> f64 {
+    balance.max(0.0) * (expense_ratio + advisory_fee) / 100.0 / 12.0
+}
+
+/// Tax due if a portfolio worth `balance` is cashed out
+fn portfolio_liquidation_tax(inputs: &Inputs, balance: f64, cost_basis: f64) -> f64 {
+    let gain = (balance - cost_basis).max(0.0);
+    match inputs.account_type {
+        AccountType::Taxable => gain * inputs.capital_gains_rate / 100.0,
+        AccountType::NonDeductible => gain * inputs.marginal_tax_rate / 100.0,
+        AccountType::TaxFree => 0.0,
+    }
+}
+
+/// Factor that converts dollars `months_elapsed` from now into today's dollars
+/// (1.0 unless reporting in real dollars)
+fn deflator(inputs: &Inputs, months_elapsed: u32) -> f64 {
+    if !inputs.real_dollars {
+        return 1.0;
+    }
+    (1.0 + inputs.inflation_rate / 100.0).powf(-(months_elapsed as f64) / 12.0)
+}
+
+/// First month after which buy net worth exceeds rent net worth for the rest of the horizon,
+/// given `(buy, rent)` net worth for each month in order
+fn find_break_even(net_worths: &[(f64, f64)]) -> Option<BreakEven> {
+    let first_ahead = net_worths
+        .iter()
+        .rposition(|(buy, rent)| buy <= rent)
+        .map_or(0, |last_behind| last_behind + 1);
+    (first_ahead < net_worths.len()).then(|| {
+        let month = first_ahead as u32 + 1;
+        BreakEven { month, year: (month - 1) / 12 + 1 }
+    })
+}
+
 /// Calculate remaining mortgage balance after a certain number of months
 pub fn remaining_balance(principal: f64, annual_rate: f64, years: u32, months_paid: u32) -> f64 {
     if annual_rate == 0.0 {
@@ -149,146 +1060,667 @@ pub fn remaining_balance(principal: f64, annual_rate: f64, years: u32, months_pa
         - monthly_payment * ((1.0 + monthly_rate).powf(months_paid as f64) - 1.0) / monthly_rate
 }
 
+/// Realized yearly rates (percent) that replace the fixed growth assumptions.
+/// Index 0 is the first year; years past the end of a series use the rate in `Inputs`.
+/// The market return moves each side by how far it lands from `investment_anchor`,
+/// scaled by the side's equity share, so each keeps its own expected return and fees.
+#[derive(Clone, Debug, Default)]
+pub struct MarketPath {
+    pub home_appreciation: Vec<f64>,
+    pub rent_increase: Vec<f64>,
+    pub investment_return: Vec<f64>,
+    pub investment_anchor: f64, // Expected return the market series is measured against
+}
+
+impl MarketPath {
+    /// The two sides' expected returns weighted by how much each holds in stocks,
+    /// a neutral centre for the equity market path
+    pub fn neutral_investment_return(inputs: &Inputs) -> f64 {
+        let buyer_weight = inputs.buyer_equity_share.max(0.0);
+        let renter_weight = inputs.renter_equity_share.max(0.0);
+        if buyer_weight + renter_weight == 0.0 {
+            return (inputs.buyer_investment_return + inputs.renter_investment_return) / 2.0;
+        }
+        (inputs.buyer_investment_return * buyer_weight + inputs.renter_investment_return * renter_weight)
+            / (buyer_weight + renter_weight)
+    }
+
+    fn rate(series: &[f64], year_index: usize, fallback: f64) -> f64 {
+        series.get(year_index).copied().unwrap_or(fallback)
+    }
+
+    /// One side's return for the year, shifted by its stock holdings' share of the market's surprise
+    fn investment_rate(&self, year_index: usize, expected: f64, equity_share: f64) -> f64 {
+        match self.investment_return.get(year_index) {
+            Some(market) => (expected + (market - self.investment_anchor) * equity_share / 100.0).max(-99.0),
+            None => expected,
+        }
+    }
+}
+
+/// How much of the result to build
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Detail {
+    Full,    // Month-by-month schedule and the loan payoff comparison, for display
+    Summary, // Totals and yearly snapshots only, for sweeps and simulations
+}
+
 pub fn calculate(inputs: &Inputs) -> CalculationResult {
+    calculate_with_path(inputs, &MarketPath::default(), Detail::Full)
+}
+
+/// Run the comparison with year-by-year market rates
+pub fn calculate_with_path(inputs: &Inputs, path: &MarketPath, detail: Detail) -> CalculationResult {
     let down_payment = inputs.home_price * inputs.down_payment_percent / 100.0;
     let loan_amount = inputs.home_price - down_payment;
     let closing_costs = inputs.home_price * inputs.closing_cost_percent / 100.0;
-    let initial_investment = down_payment + closing_costs;
 
-    let monthly_mortgage = calculate_monthly_payment(loan_amount, inputs.mortgage_rate, inputs.loan_term_years);
-    let monthly_home_insurance = inputs.home_insurance / 12.0;
-    let monthly_renters_insurance = inputs.renters_insurance / 12.0;
+    let mut mortgage = Mortgage::new(inputs, loan_amount);
 
-    let monthly_investment_return = inputs.investment_return / 100.0 / 12.0;
-    let monthly_appreciation = inputs.home_appreciation / 100.0 / 12.0;
+    // Points (and a buy-down the buyer funds) are paid at closing; the renter invests the same cash
+    let points_cost = loan_amount * inputs.discount_points / 100.0;
+    let buy_down_cost = if inputs.buy_down_seller_paid { 0.0 } else { mortgage.buy_down_cost() };
+    let initial_investment = down_payment + closing_costs + points_cost + buy_down_cost;
+
+    // Basis for capital gains when the home is sold
+    let mut home_cost_basis = inputs.home_price + closing_costs;
+    let monthly_pmi = loan_amount * inputs.pmi_rate / 100.0 / 12.0;
+    let interest_debt_limit = inputs.filing_status.mortgage_interest_debt_limit();
+    // Premiums and dues escalate every year
+    let mut monthly_home_insurance = inputs.home_insurance / 12.0;
+    let mut monthly_landlord_insurance = inputs.landlord_insurance / 12.0;
+    let mut monthly_hoa = inputs.hoa_monthly;
+    let mut monthly_renters_insurance = inputs.renters_insurance / 12.0;
 
     let total_months = inputs.time_horizon_years * 12;
 
     // === BUY SCENARIO TRACKING ===
     let mut total_mortgage_payments = 0.0;
+    let mut total_interest_paid = 0.0;
+    let mut total_principal_paid = 0.0;
+    let mut total_extra_principal = 0.0;
+    let mut total_buy_down_subsidy = 0.0;
+    let mut total_cash_out = 0.0;
+    let mut refinances = Vec::new();
+    let mut initial_mortgage_payment = 0.0;
+    let mut peak_mortgage_payment: f64 = 0.0;
+    let mut total_pmi = 0.0;
     let mut total_property_tax = 0.0;
     let mut total_home_insurance = 0.0;
     let mut total_hoa = 0.0;
     let mut total_maintenance = 0.0;
+    let mut capex = Vec::new();
+    let mut total_capex = 0.0;
+    let mut improvements = Vec::new();
+    let mut total_improvements = 0.0;
+    let mut heloc_balance = 0.0;
+    let mut heloc_payment = 0.0;
+    let mut total_heloc_payments = 0.0;
+    let mut total_heloc_interest = 0.0;
     let mut current_home_value = inputs.home_price;
+    // The purchase resets the assessment to the price paid
+    let mut assessed_value = inputs.home_price;
     let mut total_buy_monthly_costs = 0.0;
+    let mut total_buy_one_time_costs = 0.0;
+
+    // PMI applies from the start when the down payment is under 20%
+    let mut pmi_active = loan_amount > inputs.home_price * PMI_REQUIRED_LTV / 100.0;
 
     // Buyer's investment account (for when buying is cheaper than renting)
     let mut buyer_investment_balance = 0.0;
     let mut buyer_total_contributions = 0.0;
+    let mut buyer_cost_basis = 0.0;
+
+    // Deductions build up over each tax year and are settled at year end
+    let mut year_deductible_interest = 0.0;
+    let mut year_property_tax = 0.0;
+    let mut total_tax_savings = 0.0;
+    let mut total_tax_savings_invested = 0.0; // After any tax on rental income
+
+    // Rental income from part of the home; when it's taxed, the rented share of
+    // expenses moves from itemized deductions to the rental, which also depreciates
+    let mut gross_rental_income = inputs.rental_income_monthly;
+    let taxed_rental_share = if inputs.rental_income_taxed && inputs.rental_income_monthly > 0.0 {
+        inputs.rental_share_percent / 100.0
+    } else {
+        0.0
+    };
+    let mut full_depreciation = home_cost_basis * DEPRECIABLE_BUILDING_SHARE / (RESIDENTIAL_DEPRECIATION_YEARS * 12.0);
+    let mut accumulated_depreciation = 0.0;
+    let mut year_rental_profit = 0.0;
+    let mut suspended_rental_loss = 0.0;
+    let mut total_rental_income = 0.0;
+    let mut total_rental_income_tax = 0.0;
+
+    // After moving out, the whole home is let at market rent and the owner rents elsewhere
+    let move_out_month = if inputs.exit_strategy.keeps_as_rental() {
+        (inputs.move_out_year * 12).min(total_months)
+    } else {
+        total_months
+    };
+    let months_rented = total_months - move_out_month;
+    let holds_rental = inputs.exit_strategy == ExitStrategy::RentOutAndHold && months_rented > 0;
+    let mut landlord_rent = inputs.landlord_rent;
+    let mut owner_rent = 0.0;
+    let mut total_owner_rent = 0.0;
+
+    let mut buyer_dividend_tax = 0.0;
+    let mut buyer_fees = 0.0;
 
     // === RENT SCENARIO TRACKING ===
     let mut total_rent_paid = 0.0;
     let mut total_renters_insurance = 0.0;
     let mut current_rent = inputs.monthly_rent;
+    let mut market_rent = inputs.monthly_rent;
     let mut total_rent_monthly_costs = 0.0;
+    let mut total_rent_one_time_costs = 0.0;
+    let mut total_rental_fees = 0.0;
+    let mut total_moving_costs = 0.0;
+    let mut moves = 0;
+
+    // Signing the first lease: deposit plus fees
+    let lease_fees = |rent: f64| inputs.application_fee + rent * 12.0 * inputs.broker_fee_percent / 100.0;
+    let mut security_deposit = inputs.monthly_rent * inputs.security_deposit_months;
+    let renter_upfront_costs = security_deposit + lease_fees(inputs.monthly_rent);
+    total_rental_fees += lease_fees(inputs.monthly_rent);
 
-    // Renter invests the down payment + closing costs
+    // Renter invests the down payment + closing costs, less what the lease takes up front,
     // PLUS any monthly savings when renting is cheaper
-    let mut renter_investment_balance = initial_investment;
+    let renter_initial_investment = initial_investment - renter_upfront_costs;
+    let mut renter_investment_balance = renter_initial_investment;
     let mut renter_monthly_contributions = 0.0;
+    let mut renter_cost_basis = renter_initial_investment;
+    let mut renter_dividend_tax = 0.0;
+    let mut renter_fees = 0.0;
 
     let mut yearly_snapshots = Vec::new();
+    let mut monthly_schedule = Vec::new();
+    let mut net_worths = Vec::with_capacity(total_months as usize); // (buy, rent) each month
 
     for month in 1..=total_months {
+        // The simulation runs in nominal dollars; in real-dollar mode every reported
+        // amount is converted to today's dollars (flows at the start of the month,
+        // balances at the end)
+        let flow_deflator = deflator(inputs, month - 1);
+        let stock_deflator = deflator(inputs, month);
+
+        let year_index = ((month - 1) / 12) as usize;
+        let buyer_monthly_return =
+            path.investment_rate(year_index, inputs.buyer_investment_return, inputs.buyer_equity_share) / 100.0 / 12.0;
+        let renter_monthly_return =
+            path.investment_rate(year_index, inputs.renter_investment_return, inputs.renter_equity_share) / 100.0 / 12.0;
+        let monthly_appreciation =
+            MarketPath::rate(&path.home_appreciation, year_index, inputs.home_appreciation) / 100.0 / 12.0;
+
+        let rented_out = month > move_out_month;
+        if month == move_out_month + 1 {
+            // The owner signs a lease at market rent
+            owner_rent = market_rent;
+            // A home converted to a rental depreciates from the lower of its
+            // basis and its market value at conversion
+            full_depreciation = home_cost_basis.min(current_home_value) * DEPRECIABLE_BUILDING_SHARE
+                / (RESIDENTIAL_DEPRECIATION_YEARS * 12.0);
+        }
+        let owner_rent_this_month = if rented_out { owner_rent + monthly_renters_insurance } else { 0.0 };
+        let rental_share = if rented_out { 1.0 } else { taxed_rental_share };
+        let insurance_this_month = if rented_out { monthly_landlord_insurance } else { monthly_home_insurance };
+
+        // Renovations are paid for when they're done and add to the home's value,
+        // its assessment and its cost basis
+        let mut buy_one_time_costs = 0.0;
+        for project in inputs.improvements.iter().filter(|p| p.month == month) {
+            match project.funding {
+                FundingSource::Cash => buy_one_time_costs += project.cost,
+                FundingSource::Heloc => {
+                    heloc_balance += project.cost;
+                    heloc_payment = amortized_payment(heloc_balance, inputs.heloc_rate, inputs.heloc_term_years.max(1) * 12);
+                }
+                FundingSource::Mortgage => mortgage.borrow(project.cost),
+            }
+            let value_added = current_home_value * project.value_uplift_percent / 100.0;
+            current_home_value += value_added;
+            assessed_value += value_added;
+            home_cost_basis += project.cost;
+            total_improvements += project.cost * flow_deflator;
+            improvements.push(ImprovementSummary {
+                name: project.name.clone(),
+                month,
+                cost: project.cost * flow_deflator,
+                funding: project.funding,
+                value_added: value_added * flow_deflator,
+            });
+        }
+
         // === CALCULATE MONTHLY COSTS ===
 
-        // Buy: mortgage (if still paying) + taxes + insurance + HOA + maintenance
-        let paying_mortgage = month <= inputs.loan_term_years * 12;
-        let mortgage_this_month = if paying_mortgage { monthly_mortgage } else { 0.0 };
+        // Buy: mortgage (if still paying) + PMI + taxes + insurance + HOA + maintenance
+        let balance_before_payment = mortgage.balance;
+        let mortgage_this_month = mortgage.pay_month(inputs, month, extra_principal_for_month(inputs, month));
+        let interest_this_month = mortgage_this_month.interest;
+
+        let heloc_balance_before_payment = heloc_balance;
+        let heloc_interest_this_month = heloc_balance * inputs.heloc_rate / 100.0 / 12.0;
+        let heloc_payment_this_month = heloc_payment.min(heloc_balance + heloc_interest_this_month).max(0.0);
+        heloc_balance -= heloc_payment_this_month - heloc_interest_this_month;
+        total_heloc_payments += heloc_payment_this_month * flow_deflator;
+        total_heloc_interest += heloc_interest_this_month * flow_deflator;
+
+        // Refinance closing costs are paid in cash; cash-out goes to the buyer's investments
+        if let Some(refi) = mortgage_this_month.refinance {
+            buy_one_time_costs += refi.closing_costs;
+            buyer_investment_balance += refi.cash_out;
+            buyer_cost_basis += refi.cash_out;
+            total_cash_out += refi.cash_out * flow_deflator;
+            refinances.push(RefinanceSummary {
+                closing_costs: refi.closing_costs * flow_deflator,
+                cash_out: refi.cash_out * flow_deflator,
+                new_payment: refi.new_payment * flow_deflator,
+                monthly_savings: refi.monthly_savings * flow_deflator,
+                ..refi
+            });
+        }
+
+        let extra_principal_this_month = mortgage_this_month.extra_principal;
+        let subsidy_this_month = mortgage_this_month.subsidy;
+        let mortgage_this_month = mortgage_this_month.payment;
+        // What the buyer actually pays after any buy-down subsidy
+        let buyer_payment_this_month = mortgage_this_month - subsidy_this_month;
+        let pmi_this_month = if pmi_active { monthly_pmi } else { 0.0 };
+
+        if month == 1 {
+            initial_mortgage_payment = buyer_payment_this_month * flow_deflator;
+        }
+        peak_mortgage_payment = peak_mortgage_payment.max(buyer_payment_this_month * flow_deflator);
+
+        // Interest is only deductible on the first $750K ($375K) of mortgage debt;
+        // a HELOC spent improving the home counts toward it
+        let personal_share = 1.0 - rental_share;
+        let secured_debt = balance_before_payment + heloc_balance_before_payment;
+        if secured_debt > 0.0 {
+            year_deductible_interest += (interest_this_month + heloc_interest_this_month)
+                * (interest_debt_limit / secured_debt).min(1.0)
+                * personal_share;
+        }
+        // Points on a purchase loan are deductible in the year they're paid
+        if month == 1 {
+            year_deductible_interest += points_cost * (interest_debt_limit / loan_amount.max(1.0)).min(1.0);
+        }
+
+        // A rental isn't the owner's homestead
+        let exemption = if rented_out { 0.0 } else { inputs.homestead_exemption };
+        let property_tax_this_month = if inputs.assessment_capped {
+            (assessed_value - exemption).max(0.0) * inputs.millage_rate / 1000.0 / 12.0
+        } else {
+            (current_home_value - exemption).max(0.0) * inputs.property_tax_rate / 100.0 / 12.0
+        };
+        // Costs quoted in today's dollars rise with inflation
+        let price_level = (1.0 + inputs.inflation_rate / 100.0).powf((month - 1) as f64 / 12.0);
+        let routine_maintenance = if inputs.capex_replaces_maintenance {
+            0.0
+        } else {
+            current_home_value * inputs.maintenance_percent / 100.0
+        };
+        let maintenance_this_month = routine_maintenance.max(inputs.maintenance_floor * price_level) / 12.0;
+
+        // Major replacements fall due on their own schedule and add to the cost basis
+        let capex_due: Vec<&CapexItem> = inputs.capex_items.iter().filter(|item| item.is_due(month)).collect();
+        let capex_this_month = capex_due.iter().map(|item| item.cost * price_level).sum::<f64>();
+        for item in &capex_due {
+            capex.push(CapexReplacement {
+                name: item.name.clone(),
+                month,
+                cost: item.cost * price_level * flow_deflator,
+            });
+        }
+        home_cost_basis += capex_this_month;
 
-        let property_tax_this_month = current_home_value * inputs.property_tax_rate / 100.0 / 12.0;
-        let maintenance_this_month = current_home_value * inputs.maintenance_percent / 100.0 / 12.0;
+        // Rent collected from tenants, after vacancy and the manager's cut
+        let rental_income_this_month = if rented_out {
+            landlord_rent
+                * (1.0 - inputs.landlord_vacancy_percent / 100.0)
+                * (1.0 - inputs.landlord_management_percent / 100.0)
+        } else {
+            gross_rental_income
+                * (1.0 - inputs.rental_vacancy_percent / 100.0)
+                * (1.0 - inputs.rental_management_percent / 100.0)
+        };
+        if rental_share > 0.0 {
+            let rental_expenses = (interest_this_month
+                + heloc_interest_this_month
+                + pmi_this_month
+                + property_tax_this_month
+                + insurance_this_month
+                + monthly_hoa
+                + maintenance_this_month)
+                * rental_share;
+            // Depreciation stops once the rented share of the building is written off
+            let depreciable_basis = full_depreciation * RESIDENTIAL_DEPRECIATION_YEARS * 12.0 * rental_share;
+            let depreciation = (full_depreciation * rental_share).min(depreciable_basis - accumulated_depreciation).max(0.0);
+            year_rental_profit += rental_income_this_month - rental_expenses - depreciation;
+            accumulated_depreciation += depreciation;
+        }
+        total_rental_income += rental_income_this_month * flow_deflator;
+        total_owner_rent += owner_rent_this_month * flow_deflator;
 
-        let buy_monthly_cost = mortgage_this_month
+        // Prepayments come out of the buyer's budget like any other housing cost;
+        // rental income offsets it
+        let buy_monthly_cost = buyer_payment_this_month
+            + extra_principal_this_month
+            + pmi_this_month
             + property_tax_this_month
-            + monthly_home_insurance
-            + inputs.hoa_monthly
-            + maintenance_this_month;
+            + insurance_this_month
+            + monthly_hoa
+            + maintenance_this_month
+            + capex_this_month
+            + heloc_payment_this_month
+            + owner_rent_this_month
+            - rental_income_this_month;
+
+        // Moving resets rent to market and means a new lease, deposit and movers
+        let mut rent_one_time_costs = 0.0;
+        let move_months = inputs.move_every_years * 12;
+        if move_months > 0 && month > 1 && (month - 1).is_multiple_of(move_months) {
+            current_rent = market_rent;
+            let new_deposit = current_rent * inputs.security_deposit_months;
+            let fees = lease_fees(current_rent);
+            let moving = inputs.moving_cost * price_level;
+            rent_one_time_costs += new_deposit - security_deposit + fees + moving;
+            security_deposit = new_deposit;
+            total_rental_fees += fees * flow_deflator;
+            total_moving_costs += moving * flow_deflator;
+            total_rent_one_time_costs += (fees + moving) * flow_deflator;
+            moves += 1;
+        }
 
         // Rent: rent + renter's insurance
+        let rent_this_month = current_rent;
         let rent_monthly_cost = current_rent + monthly_renters_insurance;
 
         // Track totals
-        total_buy_monthly_costs += buy_monthly_cost;
-        total_rent_monthly_costs += rent_monthly_cost;
+        total_buy_monthly_costs += buy_monthly_cost * flow_deflator;
+        total_buy_one_time_costs += buy_one_time_costs * flow_deflator;
+        total_rent_monthly_costs += rent_monthly_cost * flow_deflator;
 
         // === UPDATE BUY SCENARIO ===
-        total_mortgage_payments += mortgage_this_month;
-        total_property_tax += property_tax_this_month;
-        total_home_insurance += monthly_home_insurance;
-        total_hoa += inputs.hoa_monthly;
-        total_maintenance += maintenance_this_month;
+        let principal_this_month = mortgage_this_month + extra_principal_this_month - interest_this_month;
+        total_mortgage_payments += (buyer_payment_this_month + extra_principal_this_month) * flow_deflator;
+        total_buy_down_subsidy += subsidy_this_month * flow_deflator;
+        total_extra_principal += extra_principal_this_month * flow_deflator;
+        total_interest_paid += interest_this_month * flow_deflator;
+        total_principal_paid += principal_this_month * flow_deflator;
+        total_pmi += pmi_this_month * flow_deflator;
+        total_property_tax += property_tax_this_month * flow_deflator;
+        year_property_tax += property_tax_this_month * personal_share;
+        total_home_insurance += insurance_this_month * flow_deflator;
+        total_hoa += monthly_hoa * flow_deflator;
+        total_maintenance += maintenance_this_month * flow_deflator;
+        total_capex += capex_this_month * flow_deflator;
         current_home_value *= 1.0 + monthly_appreciation;
 
+        if pmi_active {
+            pmi_active = !pmi_can_cancel(inputs, loan_amount, month, mortgage.balance, current_home_value);
+        }
+
         // === UPDATE RENT SCENARIO ===
-        total_rent_paid += current_rent;
-        total_renters_insurance += monthly_renters_insurance;
+        total_rent_paid += current_rent * flow_deflator;
+        total_renters_insurance += monthly_renters_insurance * flow_deflator;
 
         // === INVESTMENT LOGIC ===
         // Whoever spends less on housing invests the difference
 
-        // Buyer's investments grow
-        buyer_investment_balance *= 1.0 + monthly_investment_return;
+        // Buyer's investments grow, less fund expenses and advisory fees
+        buyer_investment_balance *= 1.0 + buyer_monthly_return;
+        let buyer_fee = monthly_portfolio_fee(buyer_investment_balance, inputs.buyer_expense_ratio, inputs.buyer_advisory_fee);
+        buyer_investment_balance -= buyer_fee;
+        buyer_fees += buyer_fee * flow_deflator;
+
+        // Renter's investments grow, less fund expenses and advisory fees
+        renter_investment_balance *= 1.0 + renter_monthly_return;
+        let renter_fee =
+            monthly_portfolio_fee(renter_investment_balance, inputs.renter_expense_ratio, inputs.renter_advisory_fee);
+        renter_investment_balance -= renter_fee;
+        renter_fees += renter_fee * flow_deflator;
+
+        // Dividend taxes drag on both portfolios; after-tax dividends are
+        // reinvested and add to the cost basis
+        let (buyer_dividends, buyer_tax) = monthly_dividend_tax(inputs, buyer_investment_balance);
+        buyer_investment_balance -= buyer_tax;
+        buyer_cost_basis += buyer_dividends - buyer_tax;
+        buyer_dividend_tax += buyer_tax * flow_deflator;
+
+        let (renter_dividends, renter_tax) = monthly_dividend_tax(inputs, renter_investment_balance);
+        renter_investment_balance -= renter_tax;
+        renter_cost_basis += renter_dividends - renter_tax;
+        renter_dividend_tax += renter_tax * flow_deflator;
+
+        // Income tax is settled at the end of each tax year
+        if month.is_multiple_of(12) {
+            // Each side itemizes only when it beats the standard deduction. State income
+            // tax, paid either way, fills the SALT cap before property tax does, so the
+            // buyer saves only on the deductions renting wouldn't have had. The deduction
+            // and state tax rise with inflation.
+            let tax_year = month / 12;
+            let indexation = (1.0 + inputs.inflation_rate / 100.0).powi(tax_year as i32 - 1);
+            let standard_deduction = inputs.standard_deduction * indexation;
+            let state_income_tax = inputs.state_income_tax * indexation;
+            let renter_deduction = standard_deduction.max(state_income_tax.min(inputs.salt_cap));
+            let buyer_deduction = standard_deduction
+                .max(year_deductible_interest + (year_property_tax + state_income_tax).min(inputs.salt_cap));
+            let tax_savings = (buyer_deduction - renter_deduction).max(0.0) * inputs.marginal_tax_rate / 100.0;
 
-        // Renter's investments grow
-        renter_investment_balance *= 1.0 + monthly_investment_return;
+            // Rental profit is taxed as ordinary income; losses can't offset other
+            // income (passive loss rules), so they carry forward against later profit
+            let rental_profit = year_rental_profit - suspended_rental_loss;
+            suspended_rental_loss = (-rental_profit).max(0.0);
+            let rental_income_tax = rental_profit.max(0.0) * inputs.marginal_tax_rate / 100.0;
 
-        if buy_monthly_cost < rent_monthly_cost {
+            // Tax saved goes into the buyer's investments; a net bill is paid from
+            // the housing budget like any other cost
+            let net_tax = rental_income_tax - tax_savings;
+            if net_tax > 0.0 {
+                buy_one_time_costs += net_tax;
+                total_buy_one_time_costs += net_tax * flow_deflator;
+            } else {
+                buyer_investment_balance -= net_tax;
+                buyer_cost_basis -= net_tax;
+                total_tax_savings_invested -= net_tax * stock_deflator;
+            }
+            total_tax_savings += tax_savings * stock_deflator;
+            total_rental_income_tax += rental_income_tax * stock_deflator;
+            year_deductible_interest = 0.0;
+            year_property_tax = 0.0;
+            year_rental_profit = 0.0;
+        }
+
+        // One-time costs come out of the same budget
+        let buy_cash_outflow = buy_monthly_cost + buy_one_time_costs;
+        let rent_cash_outflow = rent_monthly_cost + rent_one_time_costs;
+        let (buyer_contribution, renter_contribution) = if buy_cash_outflow < rent_cash_outflow {
             // Buying is cheaper - BUYER invests the difference
-            let savings = rent_monthly_cost - buy_monthly_cost;
+            let savings = rent_cash_outflow - buy_cash_outflow;
             buyer_investment_balance += savings;
-            buyer_total_contributions += savings;
+            buyer_cost_basis += savings;
+            buyer_total_contributions += savings * flow_deflator;
+            (savings, 0.0)
         } else {
             // Renting is cheaper - RENTER invests the difference
-            let savings = buy_monthly_cost - rent_monthly_cost;
+            let savings = buy_cash_outflow - rent_cash_outflow;
             renter_investment_balance += savings;
-            renter_monthly_contributions += savings;
-        }
+            renter_cost_basis += savings;
+            renter_monthly_contributions += savings * flow_deflator;
+            (0.0, savings)
+        };
 
-        // Rent increases annually
-        if month % 12 == 0 {
-            current_rent *= 1.0 + inputs.rent_increase_rate / 100.0;
+        // Rent increases annually; renewals can't rise faster than the cap
+        if month.is_multiple_of(12) {
+            let market_increase = MarketPath::rate(&path.rent_increase, year_index, inputs.rent_increase_rate);
+            market_rent *= 1.0 + market_increase / 100.0;
+            current_rent *= 1.0 + market_increase.min(inputs.renewal_increase_cap) / 100.0;
+            landlord_rent *= 1.0 + market_increase / 100.0;
+            owner_rent *= 1.0 + market_increase.min(inputs.renewal_increase_cap) / 100.0;
 
-            // Record yearly snapshot
-            let year = month / 12;
-            let months_paid = month.min(inputs.loan_term_years * 12);
-            let remaining_mort = remaining_balance(loan_amount, inputs.mortgage_rate, inputs.loan_term_years, months_paid);
+            monthly_home_insurance *= 1.0 + inputs.home_insurance_increase / 100.0;
+            monthly_landlord_insurance *= 1.0 + inputs.home_insurance_increase / 100.0;
+            monthly_hoa *= 1.0 + inputs.hoa_increase / 100.0;
+            monthly_renters_insurance *= 1.0 + inputs.renters_insurance_increase / 100.0;
+
+            // The assessment follows the market, but no faster than the cap
+            // and never above market value
+            assessed_value = (assessed_value * (1.0 + inputs.assessment_cap / 100.0)).min(current_home_value);
+
+            gross_rental_income *= 1.0 + inputs.rental_income_growth / 100.0;
+        }
 
+        // Net worth if the home were sold and both portfolios cashed out this month;
+        // a rental that's being held is valued at its equity
+        let remaining_mort = mortgage.balance;
+        let (selling_costs_now, sale_tax_now) = if holds_rental && rented_out {
+            (0.0, 0.0)
+        } else {
             let selling_costs_now = current_home_value * inputs.selling_cost_percent / 100.0;
-            let buy_net_worth = current_home_value - remaining_mort - selling_costs_now + buyer_investment_balance;
-            let rent_net_worth = renter_investment_balance;
+            let months_rented_now = month.saturating_sub(move_out_month);
+            let sale_tax_now = home_sale_capital_gains_tax(
+                inputs,
+                current_home_value,
+                selling_costs_now,
+                home_cost_basis,
+                month,
+                months_rented_now,
+            ) + depreciation_recapture_tax(
+                inputs,
+                accumulated_depreciation,
+                current_home_value,
+                selling_costs_now,
+                home_cost_basis,
+            );
+            (selling_costs_now, sale_tax_now)
+        };
+        let buyer_portfolio_tax = portfolio_liquidation_tax(inputs, buyer_investment_balance, buyer_cost_basis);
+        let renter_portfolio_tax = portfolio_liquidation_tax(inputs, renter_investment_balance, renter_cost_basis);
+
+        let equity = current_home_value - remaining_mort - heloc_balance;
+        let buy_net_worth = equity - selling_costs_now - sale_tax_now + buyer_investment_balance - buyer_portfolio_tax;
+        let rent_net_worth = renter_investment_balance - renter_portfolio_tax + security_deposit;
 
+        // Record yearly snapshot
+        if month.is_multiple_of(12) {
+            let year = month / 12;
             yearly_snapshots.push(YearlySnapshot {
                 year,
-                buy_net_worth,
-                rent_net_worth,
+                buy_net_worth: buy_net_worth * stock_deflator,
+                rent_net_worth: rent_net_worth * stock_deflator,
+                home_value: current_home_value * stock_deflator,
+                mortgage_balance: (remaining_mort + heloc_balance) * stock_deflator,
+                equity: equity * stock_deflator,
+                selling_costs: selling_costs_now * stock_deflator,
+                home_sale_tax: sale_tax_now * stock_deflator,
+                buyer_portfolio: buyer_investment_balance * stock_deflator,
+                buyer_portfolio_tax: buyer_portfolio_tax * stock_deflator,
+                buy_cumulative_spend: initial_investment + total_buy_monthly_costs + total_buy_one_time_costs,
+                buy_monthly_cost: buy_monthly_cost * flow_deflator,
+                renter_portfolio: renter_investment_balance * stock_deflator,
+                renter_portfolio_tax: renter_portfolio_tax * stock_deflator,
+                rent_cumulative_spend: total_rent_monthly_costs + total_rent_one_time_costs + lease_fees(inputs.monthly_rent),
+                current_rent: rent_this_month * flow_deflator,
             });
         }
+
+        net_worths.push((buy_net_worth, rent_net_worth));
+        if detail == Detail::Summary {
+            continue;
+        }
+        monthly_schedule.push(MonthlyRow {
+            month,
+            payment: (buyer_payment_this_month + extra_principal_this_month) * flow_deflator,
+            interest: interest_this_month * flow_deflator,
+            principal: principal_this_month * flow_deflator,
+            balance: mortgage.balance * stock_deflator,
+            pmi: pmi_this_month * flow_deflator,
+            property_tax: property_tax_this_month * flow_deflator,
+            insurance: insurance_this_month * flow_deflator,
+            hoa: monthly_hoa * flow_deflator,
+            maintenance: maintenance_this_month * flow_deflator,
+            capex: capex_this_month * flow_deflator,
+            capex_items: capex_due.iter().map(|item| item.name.as_str()).collect::<Vec<_>>().join(", "),
+            rental_income: rental_income_this_month * flow_deflator,
+            owner_rent: owner_rent_this_month * flow_deflator,
+            rent: rent_this_month * flow_deflator,
+            buyer_contribution: buyer_contribution * flow_deflator,
+            buyer_investment_balance: buyer_investment_balance * stock_deflator,
+            renter_contribution: renter_contribution * flow_deflator,
+            renter_investment_balance: renter_investment_balance * stock_deflator,
+            home_value: current_home_value * stock_deflator,
+            equity: equity * stock_deflator,
+            buy_net_worth: buy_net_worth * stock_deflator,
+            rent_net_worth: rent_net_worth * stock_deflator,
+        });
     }
 
     // === FINAL CALCULATIONS ===
 
-    let remaining_mortgage = remaining_balance(
-        loan_amount,
-        inputs.mortgage_rate,
-        inputs.loan_term_years,
-        total_months.min(inputs.loan_term_years * 12)
-    );
+    // A rental that's kept isn't sold, so there are no selling costs and no tax yet
+    let (selling_costs, recapture_tax, home_sale_tax) = if holds_rental {
+        (0.0, 0.0, 0.0)
+    } else {
+        let selling_costs = current_home_value * inputs.selling_cost_percent / 100.0;
+        let recapture_tax = depreciation_recapture_tax(
+            inputs,
+            accumulated_depreciation,
+            current_home_value,
+            selling_costs,
+            home_cost_basis,
+        );
+        let gains_tax = home_sale_capital_gains_tax(
+            inputs,
+            current_home_value,
+            selling_costs,
+            home_cost_basis,
+            total_months,
+            months_rented,
+        );
+        (selling_costs, recapture_tax, gains_tax + recapture_tax)
+    };
+
+    // Both portfolios are cashed out at the end of the horizon
+    let buyer_liquidation_tax = portfolio_liquidation_tax(inputs, buyer_investment_balance, buyer_cost_basis);
+    let renter_liquidation_tax = portfolio_liquidation_tax(inputs, renter_investment_balance, renter_cost_basis);
 
-    let selling_costs = current_home_value * inputs.selling_cost_percent / 100.0;
+    // Ending balances in reporting dollars
+    let final_deflator = deflator(inputs, total_months);
+    let final_home_value = current_home_value * final_deflator;
+    let remaining_mortgage = mortgage.balance * final_deflator;
+    let remaining_heloc = heloc_balance * final_deflator;
+    let selling_costs = selling_costs * final_deflator;
+    let home_sale_tax = home_sale_tax * final_deflator;
+    let recapture_tax = recapture_tax * final_deflator;
+    let buyer_investment_balance = buyer_investment_balance * final_deflator;
+    let buyer_liquidation_tax = buyer_liquidation_tax * final_deflator;
+    let renter_investment_balance = renter_investment_balance * final_deflator;
+    let renter_liquidation_tax = renter_liquidation_tax * final_deflator;
+    let security_deposit = security_deposit * final_deflator;
 
-    // Buyer's net worth = home equity + any investments from monthly savings
-    let buy_net_worth = current_home_value - remaining_mortgage - selling_costs + buyer_investment_balance;
+    // Buyer's net worth = home equity after sale costs and taxes + any investments from monthly savings
+    let buy_net_worth = final_home_value - remaining_mortgage - remaining_heloc - selling_costs - home_sale_tax
+        + buyer_investment_balance - buyer_liquidation_tax;
 
-    let total_principal_paid = loan_amount - remaining_mortgage;
-    let total_interest_paid = total_mortgage_payments - total_principal_paid;
+    // Prepayment payoff is measured over the whole loan, not just the horizon
+    let has_prepayments = inputs.extra_monthly_principal > 0.0
+        || inputs.extra_annual_principal > 0.0
+        || !inputs.extra_payments.is_empty();
+    let (interest_saved, payoff_month) = if detail == Detail::Full && has_prepayments {
+        let (scheduled_interest, _) = simulate_loan_payoff(inputs, loan_amount, false);
+        let (prepaid_interest, payoff_month) = simulate_loan_payoff(inputs, loan_amount, true);
+        (scheduled_interest - prepaid_interest, payoff_month)
+    } else {
+        (0.0, 0)
+    };
 
     // Renter's investment returns = final value - initial investment - monthly contributions
-    let renter_investment_returns = renter_investment_balance - initial_investment - renter_monthly_contributions;
+    let renter_investment_returns = renter_investment_balance - renter_initial_investment - renter_monthly_contributions;
 
     // Buyer's investment returns (if any)
-    let buyer_investment_returns = buyer_investment_balance - buyer_total_contributions;
+    let buyer_investment_returns =
+        buyer_investment_balance - buyer_total_contributions - total_tax_savings_invested - total_cash_out;
 
     // Average monthly costs for display
     let avg_buy_monthly = total_buy_monthly_costs / total_months as f64;
@@ -300,28 +1732,62 @@ pub fn calculate(inputs: &Inputs) -> CalculationResult {
         total_mortgage_payments,
         total_interest_paid,
         total_principal_paid,
+        extra_principal_paid: total_extra_principal,
+        interest_saved,
+        payoff_month,
+        points_cost,
+        points_break_even_month: points_break_even_month(inputs, loan_amount, points_cost),
+        buy_down_subsidy: total_buy_down_subsidy,
+        rental_income: total_rental_income,
+        rental_income_tax: total_rental_income_tax,
+        rent_after_move_out: total_owner_rent,
+        kept_as_rental: holds_rental,
+        depreciation_recapture_tax: recapture_tax,
+        refinances,
+        total_pmi,
         total_property_tax,
+        final_assessed_value: if inputs.assessment_capped { assessed_value * final_deflator } else { final_home_value },
         total_insurance: total_home_insurance,
         total_hoa,
         total_maintenance,
+        capex,
+        total_capex,
+        improvements,
+        total_improvements,
+        heloc_payments: total_heloc_payments,
+        heloc_interest: total_heloc_interest,
         selling_costs,
-        final_home_value: current_home_value,
+        home_cost_basis: home_cost_basis * final_deflator,
+        home_sale_tax,
+        final_home_value,
         remaining_mortgage,
+        remaining_heloc,
         net_worth: buy_net_worth,
         // New fields for buyer's investments
         monthly_savings_invested: buyer_total_contributions,
         investment_returns: buyer_investment_returns,
         investment_balance: buyer_investment_balance,
+        investment_tax_drag: buyer_dividend_tax,
+        investment_fees: buyer_fees,
+        investment_liquidation_tax: buyer_liquidation_tax,
+        tax_savings: total_tax_savings,
     };
 
     let rent_breakdown = RentBreakdown {
-        initial_investment,
+        initial_investment: renter_initial_investment,
         total_rent_paid,
         total_renters_insurance,
+        security_deposit,
+        rental_fees: total_rental_fees,
+        moving_costs: total_moving_costs,
+        moves,
         monthly_cost_savings: renter_monthly_contributions,
         investment_returns: renter_investment_returns,
         final_investment_value: renter_investment_balance,
-        net_worth: renter_investment_balance,
+        investment_tax_drag: renter_dividend_tax,
+        investment_fees: renter_fees,
+        investment_liquidation_tax: renter_liquidation_tax,
+        net_worth: renter_investment_balance - renter_liquidation_tax + security_deposit,
     };
 
     let monthly_comparison = MonthlyCostComparison {
@@ -334,10 +1800,17 @@ pub fn calculate(inputs: &Inputs) -> CalculationResult {
     let months = total_months as f64;
     let monthly_breakdown = MonthlyBreakdown {
         buy_mortgage: total_mortgage_payments / months,
+        buy_mortgage_initial: initial_mortgage_payment,
+        buy_mortgage_peak: peak_mortgage_payment,
+        buy_pmi: total_pmi / months,
         buy_property_tax: total_property_tax / months,
         buy_insurance: total_home_insurance / months,
         buy_hoa: total_hoa / months,
         buy_maintenance: total_maintenance / months,
+        buy_capex: total_capex / months,
+        buy_heloc: total_heloc_payments / months,
+        buy_rental_income: total_rental_income / months,
+        buy_rent: total_owner_rent / months,
         buy_total: avg_buy_monthly,
         rent_payment: total_rent_paid / months,
         rent_insurance: total_renters_insurance / months,
@@ -345,6 +1818,7 @@ pub fn calculate(inputs: &Inputs) -> CalculationResult {
     };
 
     let difference = buy_breakdown.net_worth - rent_breakdown.net_worth;
+    let break_even = find_break_even(&net_worths);
 
     CalculationResult {
         buy_breakdown,
@@ -353,32 +1827,91 @@ pub fn calculate(inputs: &Inputs) -> CalculationResult {
         monthly_breakdown,
         difference,
         yearly_snapshots,
+        monthly_schedule,
+        break_even,
     }
 }
 
-/// Calculate the difference (buy net worth - rent net worth) for a given input value
-pub fn calculate_difference_for_value(inputs: &Inputs, field: &str, value: f64) -> f64 {
-    let mut modified = inputs.clone();
+/// Set a numeric input by its slider field name
+fn set_field(inputs: &mut Inputs, field: &str, value: f64) {
     match field {
-        "home_price" => modified.home_price = value,
-        "down_payment_percent" => modified.down_payment_percent = value,
-        "mortgage_rate" => modified.mortgage_rate = value,
-        "loan_term_years" => modified.loan_term_years = value as u32,
-        "property_tax_rate" => modified.property_tax_rate = value,
-        "home_insurance" => modified.home_insurance = value,
-        "hoa_monthly" => modified.hoa_monthly = value,
-        "maintenance_percent" => modified.maintenance_percent = value,
-        "home_appreciation" => modified.home_appreciation = value,
-        "closing_cost_percent" => modified.closing_cost_percent = value,
-        "selling_cost_percent" => modified.selling_cost_percent = value,
-        "monthly_rent" => modified.monthly_rent = value,
-        "rent_increase_rate" => modified.rent_increase_rate = value,
-        "renters_insurance" => modified.renters_insurance = value,
-        "investment_return" => modified.investment_return = value,
-        "time_horizon_years" => modified.time_horizon_years = value as u32,
+        "home_price" => inputs.home_price = value,
+        "down_payment_percent" => inputs.down_payment_percent = value,
+        "mortgage_rate" => inputs.mortgage_rate = value,
+        "loan_term_years" => inputs.loan_term_years = value as u32,
+        "arm_index_rate" => inputs.arm_index_rate = value,
+        "arm_index_change" => inputs.arm_index_change = value,
+        "arm_margin" => inputs.arm_margin = value,
+        "arm_initial_cap" => inputs.arm_initial_cap = value,
+        "arm_periodic_cap" => inputs.arm_periodic_cap = value,
+        "arm_lifetime_cap" => inputs.arm_lifetime_cap = value,
+        "discount_points" => inputs.discount_points = value,
+        "rate_reduction_per_point" => inputs.rate_reduction_per_point = value,
+        "extra_monthly_principal" => inputs.extra_monthly_principal = value,
+        "extra_annual_principal" => inputs.extra_annual_principal = value,
+        "pmi_rate" => inputs.pmi_rate = value,
+        "property_tax_rate" => inputs.property_tax_rate = value,
+        "assessment_cap" => inputs.assessment_cap = value,
+        "homestead_exemption" => inputs.homestead_exemption = value,
+        "millage_rate" => inputs.millage_rate = value,
+        "home_insurance" => inputs.home_insurance = value,
+        "hoa_monthly" => inputs.hoa_monthly = value,
+        "heloc_rate" => inputs.heloc_rate = value,
+        "heloc_term_years" => inputs.heloc_term_years = value as u32,
+        "hoa_increase" => inputs.hoa_increase = value,
+        "home_insurance_increase" => inputs.home_insurance_increase = value,
+        "maintenance_floor" => inputs.maintenance_floor = value,
+        "renters_insurance_increase" => inputs.renters_insurance_increase = value,
+        "maintenance_percent" => inputs.maintenance_percent = value,
+        "home_appreciation" => inputs.home_appreciation = value,
+        "rental_income_monthly" => inputs.rental_income_monthly = value,
+        "rental_income_growth" => inputs.rental_income_growth = value,
+        "rental_vacancy_percent" => inputs.rental_vacancy_percent = value,
+        "rental_management_percent" => inputs.rental_management_percent = value,
+        "rental_share_percent" => inputs.rental_share_percent = value,
+        "closing_cost_percent" => inputs.closing_cost_percent = value,
+        "selling_cost_percent" => inputs.selling_cost_percent = value,
+        "move_out_year" => inputs.move_out_year = value as u32,
+        "landlord_rent" => inputs.landlord_rent = value,
+        "landlord_vacancy_percent" => inputs.landlord_vacancy_percent = value,
+        "landlord_management_percent" => inputs.landlord_management_percent = value,
+        "landlord_insurance" => inputs.landlord_insurance = value,
+        "monthly_rent" => inputs.monthly_rent = value,
+        "rent_increase_rate" => inputs.rent_increase_rate = value,
+        "renewal_increase_cap" => inputs.renewal_increase_cap = value,
+        "security_deposit_months" => inputs.security_deposit_months = value,
+        "application_fee" => inputs.application_fee = value,
+        "broker_fee_percent" => inputs.broker_fee_percent = value,
+        "move_every_years" => inputs.move_every_years = value as u32,
+        "moving_cost" => inputs.moving_cost = value,
+        "renters_insurance" => inputs.renters_insurance = value,
+        "buyer_investment_return" => inputs.buyer_investment_return = value,
+        "buyer_expense_ratio" => inputs.buyer_expense_ratio = value,
+        "buyer_advisory_fee" => inputs.buyer_advisory_fee = value,
+        "buyer_equity_share" => inputs.buyer_equity_share = value,
+        "renter_investment_return" => inputs.renter_investment_return = value,
+        "renter_expense_ratio" => inputs.renter_expense_ratio = value,
+        "renter_advisory_fee" => inputs.renter_advisory_fee = value,
+        "renter_equity_share" => inputs.renter_equity_share = value,
+        "dividend_yield" => inputs.dividend_yield = value,
+        "marginal_tax_rate" => inputs.marginal_tax_rate = value,
+        "standard_deduction" => inputs.standard_deduction = value,
+        "salt_cap" => inputs.salt_cap = value,
+        "state_income_tax" => inputs.state_income_tax = value,
+        "capital_gains_rate" => inputs.capital_gains_rate = value,
+        "home_sale_exclusion" => inputs.home_sale_exclusion = value,
+        "exclusion_min_years" => inputs.exclusion_min_years = value as u32,
+        "inflation_rate" => inputs.inflation_rate = value,
+        "time_horizon_years" => inputs.time_horizon_years = value as u32,
         _ => {}
     }
-    let result = calculate(&modified);
+}
+
+/// Calculate the difference (buy net worth - rent net worth) for a given input value
+pub fn calculate_difference_for_value(inputs: &Inputs, field: &str, value: f64) -> f64 {
+    let mut modified = inputs.clone();
+    set_field(&mut modified, field, value);
+    let result = calculate_with_path(&modified, &MarketPath::default(), Detail::Summary);
     result.difference
 }
 
@@ -393,3 +1926,565 @@ pub fn generate_sensitivity_data(inputs: &Inputs, field: &str, min: f64, max: f6
         })
         .collect()
 }
+
+/// Where buying and renting tie as one input varies
+#[derive(Clone, Debug, PartialEq)]
+pub enum BreakEvenValue {
+    Unique(f64),
+    Multiple(Vec<f64>), // Every crossing found, lowest first
+    NoSolution,         // One side wins across the whole range
+}
+
+/// Find the value of `field` in `[min, max]` where the difference is zero.
+/// Scans the range to bracket every sign change, then bisects each bracket to `tolerance`.
+pub fn solve_break_even(inputs: &Inputs, field: &str, min: f64, max: f64, tolerance: f64) -> BreakEvenValue {
+    const SCAN_STEPS: usize = 60;
+
+    let scan = generate_sensitivity_data(inputs, field, min, max, SCAN_STEPS);
+    let roots = find_roots(&scan, |value| calculate_difference_for_value(inputs, field, value), tolerance);
+
+    match roots.len() {
+        0 => BreakEvenValue::NoSolution,
+        1 => BreakEvenValue::Unique(roots[0]),
+        _ => BreakEvenValue::Multiple(roots),
+    }
+}
+
+/// Zeros of `f` given an ascending scan of `(x, f(x))`: scan points that land on zero,
+/// plus the bisection of every bracket whose ends have opposite signs
+fn find_roots(scan: &[(f64, f64)], f: impl Fn(f64) -> f64, tolerance: f64) -> Vec<f64> {
+    const MAX_ITERATIONS: usize = 100;
+
+    let mut roots: Vec<f64> = Vec::new();
+    for (i, &(mut lo, mut f_lo)) in scan.iter().enumerate() {
+        if f_lo == 0.0 {
+            roots.push(lo);
+            continue;
+        }
+        let Some(&(mut hi, f_hi)) = scan.get(i + 1) else {
+            continue;
+        };
+        // A bracket that ends on a zero is counted at that scan point
+        if f_hi == 0.0 || f_lo.signum() == f_hi.signum() {
+            continue;
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            if hi - lo <= tolerance {
+                break;
+            }
+            let mid = (lo + hi) / 2.0;
+            let f_mid = f(mid);
+            if f_mid == 0.0 {
+                lo = mid;
+                hi = mid;
+                break;
+            }
+            if f_mid.signum() == f_lo.signum() {
+                lo = mid;
+                f_lo = f_mid;
+            } else {
+                hi = mid;
+            }
+        }
+        roots.push((lo + hi) / 2.0);
+    }
+
+    // Bisection can settle within tolerance of a root found next door
+    roots.dedup_by(|a, b| (*a - *b).abs() <= tolerance);
+    roots
+}
+
+/// Outcome over a grid of two inputs; `differences[y][x]` pairs `y_values[y]` with `x_values[x]`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SensitivityGrid {
+    pub x_values: Vec<f64>,
+    pub y_values: Vec<f64>,
+    pub differences: Vec<Vec<f64>>,
+}
+
+/// One piece of the zero contour, in fractional grid coordinates (x index, y index)
+pub type ContourSegment = ((f64, f64), (f64, f64));
+
+impl SensitivityGrid {
+    /// Trace where the difference crosses zero using marching squares
+    pub fn zero_contour(&self) -> Vec<ContourSegment> {
+        let mut segments = Vec::new();
+        let d = &self.differences;
+
+        for y in 0..self.y_values.len().saturating_sub(1) {
+            for x in 0..self.x_values.len().saturating_sub(1) {
+                // Corners in order around the cell, with the edge each one starts
+                let corners = [
+                    ((x as f64, y as f64), d[y][x]),
+                    ((x as f64 + 1.0, y as f64), d[y][x + 1]),
+                    ((x as f64 + 1.0, y as f64 + 1.0), d[y + 1][x + 1]),
+                    ((x as f64, y as f64 + 1.0), d[y + 1][x]),
+                ];
+
+                let mut crossings = Vec::new();
+                for i in 0..4 {
+                    let ((x0, y0), v0) = corners[i];
+                    let ((x1, y1), v1) = corners[(i + 1) % 4];
+                    if (v0 > 0.0) != (v1 > 0.0) {
+                        let t = v0 / (v0 - v1);
+                        crossings.push((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t));
+                    }
+                }
+
+                // Two crossings make one segment; a saddle has four and is split into two
+                for pair in crossings.as_chunks::<2>().0 {
+                    segments.push((pair[0], pair[1]));
+                }
+            }
+        }
+
+        segments
+    }
+}
+
+/// Vary two inputs together over their ranges
+#[allow(clippy::too_many_arguments)]
+pub fn generate_sensitivity_grid(
+    inputs: &Inputs,
+    x_field: &str,
+    x_min: f64,
+    x_max: f64,
+    y_field: &str,
+    y_min: f64,
+    y_max: f64,
+    steps: usize,
+) -> SensitivityGrid {
+    let axis = |min: f64, max: f64| -> Vec<f64> {
+        let step_size = (max - min) / steps as f64;
+        (0..=steps).map(|i| min + step_size * i as f64).collect()
+    };
+    let x_values = axis(x_min, x_max);
+    let y_values = axis(y_min, y_max);
+
+    let differences = y_values
+        .iter()
+        .map(|&y| {
+            let mut row_inputs = inputs.clone();
+            set_field(&mut row_inputs, y_field, y);
+            x_values
+                .iter()
+                .map(|&x| calculate_difference_for_value(&row_inputs, x_field, x))
+                .collect()
+        })
+        .collect();
+
+    SensitivityGrid { x_values, y_values, differences }
+}
+
+/// How much one input moves the outcome between its low and high values
+#[derive(Clone, Debug, PartialEq)]
+pub struct TornadoBar {
+    pub field: String,
+    pub low: f64,
+    pub high: f64,
+    pub low_difference: f64,
+    pub high_difference: f64,
+}
+
+impl TornadoBar {
+    pub fn swing(&self) -> f64 {
+        (self.high_difference - self.low_difference).abs()
+    }
+}
+
+/// Move each field across its `(field, low, high)` range with everything else held,
+/// ranked from the largest swing in difference to the smallest
+pub fn tornado_analysis(inputs: &Inputs, ranges: &[(&str, f64, f64)]) -> Vec<TornadoBar> {
+    let mut bars: Vec<TornadoBar> = ranges
+        .iter()
+        .map(|&(field, low, high)| TornadoBar {
+            field: field.to_string(),
+            low,
+            high,
+            low_difference: calculate_difference_for_value(inputs, field, low),
+            high_difference: calculate_difference_for_value(inputs, field, high),
+        })
+        .collect();
+    bars.sort_by(|a, b| b.swing().total_cmp(&a.swing()));
+    bars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rent_out_and_sell(move_out_year: u32, time_horizon_years: u32, home_appreciation: f64) -> BuyBreakdown {
+        let inputs = Inputs {
+            exit_strategy: ExitStrategy::RentOutAndSell,
+            move_out_year,
+            time_horizon_years,
+            home_appreciation,
+            ..Inputs::default()
+        };
+        calculate(&inputs).buy_breakdown
+    }
+
+    fn expected_recapture(buy: &BuyBreakdown, months_rented: u32) -> f64 {
+        let depreciation = buy.home_cost_basis * DEPRECIABLE_BUILDING_SHARE / (RESIDENTIAL_DEPRECIATION_YEARS * 12.0);
+        depreciation * months_rented as f64 * 0.24
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 0.01, "expected {expected}, got {actual}");
+    }
+
+    #[test]
+    fn sale_after_renting_too_long_loses_exclusion() {
+        // Rented for 60 months, past the 36 the exclusion allows
+        let buy = rent_out_and_sell(5, 10, 3.0);
+        let gain = buy.final_home_value - buy.selling_costs - buy.home_cost_basis;
+        assert!(gain > 0.0);
+        assert_close(buy.depreciation_recapture_tax, expected_recapture(&buy, 60));
+        assert_close(buy.home_sale_tax, buy.depreciation_recapture_tax + gain * 0.15);
+    }
+
+    #[test]
+    fn sale_soon_after_moving_out_keeps_exclusion() {
+        // Rented for 12 months; the gain is under the exclusion, so only recapture is due
+        let buy = rent_out_and_sell(9, 10, 3.0);
+        let gain = buy.final_home_value - buy.selling_costs - buy.home_cost_basis;
+        assert!(gain > 0.0 && gain < FilingStatus::Single.home_sale_exclusion());
+        assert_close(buy.depreciation_recapture_tax, expected_recapture(&buy, 12));
+        assert_close(buy.home_sale_tax, buy.depreciation_recapture_tax);
+    }
+
+    #[test]
+    fn conversion_below_basis_depreciates_market_value() {
+        // Prices fall 10% a year until the owner moves out after two years, then recover
+        let inputs = Inputs { exit_strategy: ExitStrategy::RentOutAndSell, move_out_year: 2, ..Inputs::default() };
+        let path = MarketPath {
+            home_appreciation: [-10.0, -10.0].into_iter().chain([10.0; 8]).collect(),
+            ..MarketPath::default()
+        };
+        let buy = calculate_with_path(&inputs, &path, Detail::Full).buy_breakdown;
+
+        let value_at_conversion = 400_000.0 * (1.0 - 0.1 / 12.0_f64).powi(24);
+        assert!(value_at_conversion < buy.home_cost_basis);
+        let depreciation = value_at_conversion * DEPRECIABLE_BUILDING_SHARE / (RESIDENTIAL_DEPRECIATION_YEARS * 12.0);
+        assert_close(buy.depreciation_recapture_tax, depreciation * 96.0 * 0.24);
+    }
+
+    #[test]
+    fn sale_at_a_loss_owes_nothing() {
+        let buy = rent_out_and_sell(5, 10, -5.0);
+        assert_close(buy.depreciation_recapture_tax, 0.0);
+        assert_close(buy.home_sale_tax, 0.0);
+    }
+
+    #[test]
+    fn side_without_stocks_ignores_market_swings() {
+        let inputs = Inputs { renter_investment_return: 4.0, renter_equity_share: 0.0, ..Inputs::default() };
+        let path = MarketPath {
+            investment_return: vec![-37.0, 26.5, 15.1, 2.1, 16.0, 32.4, 13.7, 1.4, 12.0, 21.8],
+            investment_anchor: MarketPath::neutral_investment_return(&inputs),
+            ..MarketPath::default()
+        };
+        let deterministic = calculate(&inputs);
+        let volatile = calculate_with_path(&inputs, &path, Detail::Full);
+        assert_close(volatile.rent_breakdown.net_worth, deterministic.rent_breakdown.net_worth);
+        assert!((volatile.buy_breakdown.net_worth - deterministic.buy_breakdown.net_worth).abs() > 1_000.0);
+    }
+
+    /// Balance after each payment of a fixed-rate loan
+    fn amortization(loan: f64, annual_rate: f64, months: u32) -> Vec<f64> {
+        let rate = annual_rate / 100.0 / 12.0;
+        let payment = loan * rate / (1.0 - (1.0 + rate).powi(-(months as i32)));
+        let mut balance = loan;
+        (0..months)
+            .map(|_| {
+                balance -= payment - balance * rate;
+                balance
+            })
+            .collect()
+    }
+
+    /// Last month PMI is charged and the total paid
+    fn pmi_paid(inputs: &Inputs) -> (u32, f64) {
+        let result = calculate(inputs);
+        let charged: Vec<u32> = result.monthly_schedule.iter().filter(|row| row.pmi > 0.0).map(|row| row.month).collect();
+        // PMI runs without a gap from the first month
+        assert_eq!(charged, (1..=charged.len() as u32).collect::<Vec<_>>());
+        (charged.len() as u32, result.buy_breakdown.total_pmi)
+    }
+
+    #[test]
+    fn pmi_cancellation() {
+        // 10% down on $400k; PMI is 0.5% a year of the $360k loan
+        let low_down = Inputs { down_payment_percent: 10.0, time_horizon_years: 30, ..Inputs::default() };
+        let monthly_pmi = 360_000.0 * 0.005 / 12.0;
+        let balances = amortization(360_000.0, 6.5, 360);
+        let first_month_at = |ltv: f64| balances.iter().position(|b| *b <= 400_000.0 * ltv / 100.0).unwrap() as u32 + 1;
+
+        // Automatically at 78% of the original price on the original schedule
+        let automatic = first_month_at(78.0);
+        let (month, total) = pmi_paid(&low_down);
+        assert_eq!(month, automatic);
+        assert_close(total, automatic as f64 * monthly_pmi);
+
+        // On request at 80%
+        let requested = first_month_at(80.0);
+        assert!(requested < automatic);
+        let on_request = Inputs { pmi_request_cancellation: true, ..low_down.clone() };
+        assert_eq!(pmi_paid(&on_request).0, requested);
+
+        // On a new appraisal after two years: 75% of current value, 80% after five years
+        let appraised = (24..360)
+            .find(|&month| {
+                let value = 400_000.0 * (1.0 + 0.03 / 12.0_f64).powi(month as i32);
+                let max_ltv = if month < 60 { 75.0 } else { 80.0 };
+                balances[month as usize - 1] <= value * max_ltv / 100.0
+            })
+            .unwrap();
+        assert!(appraised < requested);
+        let on_appraisal = Inputs { pmi_current_value_cancellation: true, ..low_down.clone() };
+        let (month, total) = pmi_paid(&on_appraisal);
+        assert_eq!(month, appraised);
+        assert_close(total, appraised as f64 * monthly_pmi);
+    }
+
+    /// Rate charged in each of the first `months`, checking the payment is
+    /// re-amortized over the remaining term whenever the rate resets
+    fn arm_rates(inputs: &Inputs, months: u32) -> Vec<f64> {
+        let mut mortgage = Mortgage::new(inputs, 320_000.0);
+        (1..=months)
+            .map(|month| {
+                let (balance, rate) = (mortgage.balance, mortgage.rate);
+                let months_left = mortgage.term_months - mortgage.months_paid;
+                let paid = mortgage.pay_month(inputs, month, 0.0);
+                if mortgage.rate != rate {
+                    assert_close(paid.payment, amortized_payment(balance, mortgage.rate, months_left));
+                }
+                mortgage.rate
+            })
+            .collect()
+    }
+
+    /// `steps` lists the month each rate takes effect, in order
+    fn assert_rate_steps(rates: &[f64], steps: &[(usize, f64)]) {
+        for (i, &(start, rate)) in steps.iter().enumerate() {
+            let end = steps.get(i + 1).map_or(rates.len(), |&(next, _)| next - 1);
+            for (month, actual) in rates.iter().enumerate().take(end).skip(start - 1) {
+                assert!((actual - rate).abs() < 1e-9, "month {}: expected {rate}%, got {actual}%", month + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn arm_resets_respect_caps() {
+        // Starts at 6.5%; the index starts at 4% and the margin is 2.75%
+        let arm = |mortgage_type: MortgageType, arm_index_change| {
+            let (arm_initial_cap, arm_periodic_cap, arm_lifetime_cap) = mortgage_type.default_caps();
+            Inputs { mortgage_type, arm_index_change, arm_initial_cap, arm_periodic_cap, arm_lifetime_cap, ..Inputs::default() }
+        };
+
+        // 5/1 (2/2/5 caps), index rising 1.5 points a year: initial cap, then periodic, then lifetime
+        let rates = arm_rates(&arm(MortgageType::Arm5_1, 1.5), 120);
+        assert_rate_steps(&rates, &[(1, 6.5), (61, 8.5), (73, 10.5), (85, 11.5)]);
+
+        // 5/6 (2/1/5 caps) resets every six months, a point at a time after the first
+        let rates = arm_rates(&arm(MortgageType::Arm5_6, 1.5), 120);
+        assert_rate_steps(&rates, &[(1, 6.5), (61, 8.5), (67, 9.5), (73, 10.5), (79, 11.5)]);
+
+        // A falling index is held by the caps on the way down and never goes below the margin
+        let rates = arm_rates(&arm(MortgageType::Arm5_1, -2.0), 120);
+        assert_rate_steps(&rates, &[(1, 6.5), (61, 4.5), (73, 2.75)]);
+    }
+
+    #[test]
+    fn extra_principal_shortens_the_loan() {
+        let extra_payments = vec![ExtraPayment { month: 30, amount: 10_000.0 }];
+        let inputs = Inputs {
+            extra_monthly_principal: 200.0,
+            extra_annual_principal: 1_000.0,
+            extra_payments,
+            marginal_tax_rate: 0.0, // Keep interest deductions out of the cash flows
+            ..Inputs::default()
+        };
+        let extra_for = |month: u32| -> f64 {
+            let annual = if month.is_multiple_of(12) { 1_000.0 } else { 0.0 };
+            let one_off = if month == 30 { 10_000.0 } else { 0.0 };
+            200.0 + annual + one_off
+        };
+
+        // $320k at 6.5% over 30 years, with and without the prepayments
+        let rate: f64 = 6.5 / 100.0 / 12.0;
+        let payment = 320_000.0 * rate / (1.0 - (1.0 + rate).powi(-360));
+        let run = |with_extra: bool| {
+            let (mut balance, mut interest_paid, mut month) = (320_000.0_f64, 0.0, 0);
+            while balance > 0.0 && month < 360 {
+                month += 1;
+                let interest = balance * rate;
+                let paid = if month == 360 { balance + interest } else { payment.min(balance + interest) };
+                balance -= paid - interest;
+                interest_paid += interest;
+                if with_extra {
+                    balance -= extra_for(month).min(balance);
+                }
+            }
+            (interest_paid, month)
+        };
+        let (scheduled_interest, _) = run(false);
+        let (prepaid_interest, payoff_month) = run(true);
+
+        let result = calculate(&inputs);
+        assert_eq!(result.buy_breakdown.payoff_month, payoff_month);
+        assert!(payoff_month < 360);
+        assert_close(result.buy_breakdown.interest_saved, scheduled_interest - prepaid_interest);
+
+        // Every dollar prepaid comes out of what the buyer would otherwise have invested
+        let baseline = calculate(&Inputs { extra_monthly_principal: 0.0, extra_annual_principal: 0.0, extra_payments: Vec::new(), ..inputs.clone() });
+        for (row, base) in result.monthly_schedule.iter().zip(&baseline.monthly_schedule) {
+            let shift = (row.renter_contribution - row.buyer_contribution) - (base.renter_contribution - base.buyer_contribution);
+            assert_close(shift, extra_for(row.month));
+        }
+        let horizon_extra: f64 = (1..=120).map(extra_for).sum();
+        assert_close(result.buy_breakdown.extra_principal_paid, horizon_extra);
+    }
+
+    #[test]
+    fn points_break_even_follows_the_payments() {
+        // Two points on $320k take the rate from 6.5% to 6%
+        let fixed = Inputs { discount_points: 2.0, ..Inputs::default() };
+        let savings = amortized_payment(320_000.0, 6.5, 360) - amortized_payment(320_000.0, 6.0, 360);
+        let fixed_month = (6_400.0 / savings).ceil() as u32;
+        assert_eq!(calculate(&fixed).buy_breakdown.points_break_even_month, Some(fixed_month));
+
+        // A 5/1 ARM resets both loans to index + margin after five years, so only the
+        // smaller balance keeps saving and the points take longer to pay back
+        let arm = Inputs { mortgage_type: MortgageType::Arm5_1, ..fixed.clone() };
+        let arm_month = calculate(&arm).buy_breakdown.points_break_even_month.unwrap();
+        assert!(fixed_month > 60 && arm_month > fixed_month);
+
+        // Refinancing before the points pay back forfeits them
+        let refinance = RefinanceEvent { month: 24, rate: 5.0, term_years: 30, closing_costs: 3_000.0, cash_out: 0.0 };
+        let refinanced = Inputs { refinances: vec![refinance], ..fixed };
+        assert_eq!(calculate(&refinanced).buy_breakdown.points_break_even_month, None);
+    }
+
+    #[test]
+    fn moving_resets_rent_to_market() {
+        // Market rent grows 6% a year but renewals are capped at 2%; the renter moves every 3 years
+        let inputs = Inputs {
+            rent_increase_rate: 6.0,
+            renewal_increase_cap: 2.0,
+            move_every_years: 3,
+            broker_fee_percent: 10.0,
+            ..Inputs::default()
+        };
+        let result = calculate(&inputs);
+
+        // Moves start months 37, 73 and 109 at that year's market rent, then renew at the cap
+        let expected_rent = |month: u32| {
+            let years = (month - 1) / 12;
+            let years_since_move = years % 3;
+            2_000.0 * 1.06_f64.powi((years - years_since_move) as i32) * 1.02_f64.powi(years_since_move as i32)
+        };
+        for row in &result.monthly_schedule {
+            assert_close(row.rent, expected_rent(row.month));
+        }
+
+        let rent = &result.rent_breakdown;
+        assert_eq!(rent.moves, 3);
+        // Moving costs are quoted in today's dollars and rise with 2.5% inflation
+        assert_close(rent.moving_costs, 2_000.0 * (1.025_f64.powi(3) + 1.025_f64.powi(6) + 1.025_f64.powi(9)));
+        // A $50 application fee and a 10%-of-a-year broker fee on every lease
+        let leases = [1, 37, 73, 109].map(expected_rent);
+        assert_close(rent.rental_fees, leases.iter().map(|r| 50.0 + r * 12.0 * 0.1).sum());
+        // The deposit from the last lease comes back at the end
+        assert_close(rent.security_deposit, expected_rent(109));
+    }
+
+    #[test]
+    fn homestead_exemption_applies_without_an_assessment_cap() {
+        let tax = |homestead_exemption| calculate(&Inputs { homestead_exemption, ..Inputs::default() }).buy_breakdown.total_property_tax;
+        // $50k off the taxable value at 1.2% for 10 years
+        assert_close(tax(0.0) - tax(50_000.0), 50_000.0 * 0.012 * 10.0);
+    }
+
+    #[test]
+    fn state_income_tax_fills_the_salt_cap_first() {
+        // State income tax alone exceeds the cap, so only mortgage interest adds to the deduction
+        let inputs = Inputs { state_income_tax: 50_000.0, ..Inputs::default() };
+        let buy = calculate(&inputs).buy_breakdown;
+        assert_close(buy.tax_savings, buy.total_interest_paid * 0.24);
+        assert!(buy.tax_savings > calculate(&Inputs::default()).buy_breakdown.tax_savings);
+    }
+
+    #[test]
+    fn capex_replacements_follow_useful_life() {
+        let due = |item: &CapexItem| (1..=360).filter(|m| item.is_due(*m)).collect::<Vec<u32>>();
+        let roof = |age_years| CapexItem { name: "Roof".into(), cost: 15_000.0, useful_life_years: 10, age_years };
+
+        // New: replaced at the end of each life
+        assert_eq!(due(&roof(0)), vec![121, 241]);
+        // Partway through its life
+        assert_eq!(due(&roof(4)), vec![73, 193, 313]);
+        // Already at or past the end of its life: replaced right away, then every life after
+        assert_eq!(due(&roof(10)), vec![1, 121, 241]);
+        assert_eq!(due(&roof(25)), vec![1, 121, 241]);
+        // Ages and lives far beyond any horizon don't overflow
+        assert_eq!(due(&roof(u32::MAX)), vec![1, 121, 241]);
+        let forever = CapexItem { useful_life_years: u32::MAX, ..roof(0) };
+        assert!(due(&forever).is_empty());
+    }
+
+    #[test]
+    fn liquidation_tax_by_account_type() {
+        let tax = |account_type| {
+            let inputs = Inputs { account_type, ..Inputs::default() };
+            portfolio_liquidation_tax(&inputs, 150_000.0, 100_000.0)
+        };
+        // Default rates: 15% on capital gains, 24% ordinary income
+        assert_close(tax(AccountType::Taxable), 7_500.0);
+        assert_close(tax(AccountType::NonDeductible), 12_000.0);
+        assert_close(tax(AccountType::TaxFree), 0.0);
+
+        let loss = Inputs { account_type: AccountType::NonDeductible, ..Inputs::default() };
+        assert_close(portfolio_liquidation_tax(&loss, 90_000.0, 100_000.0), 0.0);
+    }
+
+    #[test]
+    fn break_even_needs_buying_to_stay_ahead() {
+        let month_of = |buy: &[f64]| find_break_even(&buy.iter().map(|b| (*b, 100.0)).collect::<Vec<_>>()).map(|b| b.month);
+
+        assert_eq!(month_of(&[101.0; 24]), Some(1));
+        // Ahead in month 3, behind again in month 5, ahead for good from month 6
+        assert_eq!(month_of(&[90.0, 95.0, 101.0, 102.0, 99.0, 101.0, 105.0]), Some(6));
+        // A tie isn't ahead
+        assert_eq!(month_of(&[90.0, 101.0, 100.0, 101.0]), Some(4));
+        // Crosses and falls back by the end
+        assert_eq!(month_of(&[90.0, 101.0, 102.0, 98.0]), None);
+        assert_eq!(month_of(&[90.0, 95.0, 99.0]), None);
+        assert_eq!(month_of(&[]), None);
+
+        let mut net_worths = vec![(90.0, 100.0); 14];
+        net_worths.push((101.0, 100.0));
+        assert_eq!(find_break_even(&net_worths), Some(BreakEven { month: 15, year: 2 }));
+    }
+
+    #[test]
+    fn root_on_a_scan_point_is_found_once() {
+        let f = |x: f64| x - 30.0;
+        let scan: Vec<(f64, f64)> = (0..=60).map(|i| (i as f64, f(i as f64))).collect();
+        assert_eq!(find_roots(&scan, f, 0.001), vec![30.0]);
+
+        let f = |x: f64| x - 30.25;
+        let scan: Vec<(f64, f64)> = (0..=60).map(|i| (i as f64, f(i as f64))).collect();
+        let roots = find_roots(&scan, f, 0.001);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 30.25).abs() <= 0.001);
+    }
+
+    #[test]
+    fn depreciation_stops_at_building_value() {
+        // Rented for 39 years, longer than the 27.5-year schedule
+        let buy = rent_out_and_sell(1, 40, 3.0);
+        assert_close(buy.depreciation_recapture_tax, buy.home_cost_basis * DEPRECIABLE_BUILDING_SHARE * 0.24);
+    }
+}
@@ -0,0 +1,73 @@
+//! Approximate US annual market history bundled with the app so backtests work offline.
+//!
+//! Figures are rounded and compiled from public sources; they're good enough to
+//! show how the decision would have played out, not for precise research:
+//! - Home prices: FHFA house price index, year-over-year change
+//! - Equities: S&P 500 total return with dividends reinvested
+//! - Mortgage rates: Freddie Mac 30-year fixed, annual average
+//! - Rent: CPI rent of primary residence, December over December
+//! - Inflation: CPI-U, December over December
+
+/// One calendar year of market history (all values in percent)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HistoricalYear {
+    pub year: u32,
+    pub home_price_change: f64,
+    pub equity_return: f64,
+    pub mortgage_rate: f64,
+    pub rent_change: f64,
+    pub inflation: f64,
+}
+
+pub const HISTORY: &[HistoricalYear] = &[
+    HistoricalYear { year: 1975, home_price_change: 7.50, equity_return: 37.00, mortgage_rate: 9.05, rent_change: 5.30, inflation: 6.90 },
+    HistoricalYear { year: 1976, home_price_change: 8.40, equity_return: 23.83, mortgage_rate: 8.87, rent_change: 5.50, inflation: 4.90 },
+    HistoricalYear { year: 1977, home_price_change: 13.60, equity_return: -6.98, mortgage_rate: 8.85, rent_change: 6.60, inflation: 6.70 },
+    HistoricalYear { year: 1978, home_price_change: 13.50, equity_return: 6.51, mortgage_rate: 9.64, rent_change: 7.30, inflation: 9.00 },
+    HistoricalYear { year: 1979, home_price_change: 11.80, equity_return: 18.52, mortgage_rate: 11.20, rent_change: 8.80, inflation: 13.30 },
+    HistoricalYear { year: 1980, home_price_change: 6.90, equity_return: 31.74, mortgage_rate: 13.74, rent_change: 8.90, inflation: 12.50 },
+    HistoricalYear { year: 1981, home_price_change: 4.60, equity_return: -4.70, mortgage_rate: 16.63, rent_change: 8.70, inflation: 8.90 },
+    HistoricalYear { year: 1982, home_price_change: 3.30, equity_return: 20.42, mortgage_rate: 16.04, rent_change: 7.10, inflation: 3.80 },
+    HistoricalYear { year: 1983, home_price_change: 4.20, equity_return: 22.34, mortgage_rate: 13.24, rent_change: 5.10, inflation: 3.80 },
+    HistoricalYear { year: 1984, home_price_change: 4.50, equity_return: 6.15, mortgage_rate: 13.88, rent_change: 6.40, inflation: 3.90 },
+    HistoricalYear { year: 1985, home_price_change: 5.70, equity_return: 31.24, mortgage_rate: 12.43, rent_change: 6.60, inflation: 3.80 },
+    HistoricalYear { year: 1986, home_price_change: 6.90, equity_return: 18.49, mortgage_rate: 10.19, rent_change: 5.60, inflation: 1.10 },
+    HistoricalYear { year: 1987, home_price_change: 5.50, equity_return: 5.81, mortgage_rate: 10.21, rent_change: 4.10, inflation: 4.40 },
+    HistoricalYear { year: 1988, home_price_change: 5.40, equity_return: 16.54, mortgage_rate: 10.34, rent_change: 3.90, inflation: 4.40 },
+    HistoricalYear { year: 1989, home_price_change: 4.60, equity_return: 31.48, mortgage_rate: 10.32, rent_change: 3.70, inflation: 4.60 },
+    HistoricalYear { year: 1990, home_price_change: 1.00, equity_return: -3.06, mortgage_rate: 10.13, rent_change: 4.50, inflation: 6.10 },
+    HistoricalYear { year: 1991, home_price_change: 2.60, equity_return: 30.23, mortgage_rate: 9.25, rent_change: 3.20, inflation: 3.10 },
+    HistoricalYear { year: 1992, home_price_change: 2.20, equity_return: 7.49, mortgage_rate: 8.39, rent_change: 2.50, inflation: 2.90 },
+    HistoricalYear { year: 1993, home_price_change: 2.60, equity_return: 9.97, mortgage_rate: 7.31, rent_change: 2.30, inflation: 2.70 },
+    HistoricalYear { year: 1994, home_price_change: 2.60, equity_return: 1.33, mortgage_rate: 8.38, rent_change: 2.50, inflation: 2.70 },
+    HistoricalYear { year: 1995, home_price_change: 3.00, equity_return: 37.20, mortgage_rate: 7.93, rent_change: 2.50, inflation: 2.50 },
+    HistoricalYear { year: 1996, home_price_change: 3.00, equity_return: 22.68, mortgage_rate: 7.81, rent_change: 2.80, inflation: 3.30 },
+    HistoricalYear { year: 1997, home_price_change: 3.60, equity_return: 33.10, mortgage_rate: 7.60, rent_change: 3.10, inflation: 1.70 },
+    HistoricalYear { year: 1998, home_price_change: 5.60, equity_return: 28.34, mortgage_rate: 6.94, rent_change: 3.30, inflation: 1.60 },
+    HistoricalYear { year: 1999, home_price_change: 5.90, equity_return: 20.89, mortgage_rate: 7.44, rent_change: 2.60, inflation: 2.70 },
+    HistoricalYear { year: 2000, home_price_change: 7.10, equity_return: -9.03, mortgage_rate: 8.05, rent_change: 3.60, inflation: 3.40 },
+    HistoricalYear { year: 2001, home_price_change: 6.60, equity_return: -11.85, mortgage_rate: 6.97, rent_change: 4.40, inflation: 1.60 },
+    HistoricalYear { year: 2002, home_price_change: 7.50, equity_return: -21.97, mortgage_rate: 6.54, rent_change: 3.50, inflation: 2.40 },
+    HistoricalYear { year: 2003, home_price_change: 7.70, equity_return: 28.36, mortgage_rate: 5.83, rent_change: 2.60, inflation: 1.90 },
+    HistoricalYear { year: 2004, home_price_change: 10.00, equity_return: 10.74, mortgage_rate: 5.84, rent_change: 2.60, inflation: 3.30 },
+    HistoricalYear { year: 2005, home_price_change: 9.60, equity_return: 4.83, mortgage_rate: 5.87, rent_change: 2.80, inflation: 3.40 },
+    HistoricalYear { year: 2006, home_price_change: 3.50, equity_return: 15.61, mortgage_rate: 6.41, rent_change: 4.30, inflation: 2.50 },
+    HistoricalYear { year: 2007, home_price_change: -1.40, equity_return: 5.48, mortgage_rate: 6.34, rent_change: 3.90, inflation: 4.10 },
+    HistoricalYear { year: 2008, home_price_change: -8.40, equity_return: -36.55, mortgage_rate: 6.03, rent_change: 3.30, inflation: 0.10 },
+    HistoricalYear { year: 2009, home_price_change: -4.00, equity_return: 25.94, mortgage_rate: 5.04, rent_change: 0.30, inflation: 2.70 },
+    HistoricalYear { year: 2010, home_price_change: -4.20, equity_return: 14.82, mortgage_rate: 4.69, rent_change: 0.70, inflation: 1.50 },
+    HistoricalYear { year: 2011, home_price_change: -3.50, equity_return: 2.10, mortgage_rate: 4.45, rent_change: 2.50, inflation: 3.00 },
+    HistoricalYear { year: 2012, home_price_change: 5.10, equity_return: 15.89, mortgage_rate: 3.66, rent_change: 2.70, inflation: 1.70 },
+    HistoricalYear { year: 2013, home_price_change: 7.70, equity_return: 32.15, mortgage_rate: 3.98, rent_change: 2.90, inflation: 1.50 },
+    HistoricalYear { year: 2014, home_price_change: 5.40, equity_return: 13.52, mortgage_rate: 4.17, rent_change: 3.40, inflation: 0.80 },
+    HistoricalYear { year: 2015, home_price_change: 5.60, equity_return: 1.38, mortgage_rate: 3.85, rent_change: 3.70, inflation: 0.70 },
+    HistoricalYear { year: 2016, home_price_change: 6.20, equity_return: 11.77, mortgage_rate: 3.65, rent_change: 3.90, inflation: 2.10 },
+    HistoricalYear { year: 2017, home_price_change: 6.50, equity_return: 21.61, mortgage_rate: 3.99, rent_change: 3.80, inflation: 2.10 },
+    HistoricalYear { year: 2018, home_price_change: 5.70, equity_return: -4.23, mortgage_rate: 4.54, rent_change: 3.60, inflation: 1.90 },
+    HistoricalYear { year: 2019, home_price_change: 5.20, equity_return: 31.21, mortgage_rate: 3.94, rent_change: 3.70, inflation: 2.30 },
+    HistoricalYear { year: 2020, home_price_change: 10.80, equity_return: 18.02, mortgage_rate: 3.11, rent_change: 2.40, inflation: 1.40 },
+    HistoricalYear { year: 2021, home_price_change: 17.80, equity_return: 28.47, mortgage_rate: 2.96, rent_change: 3.30, inflation: 7.00 },
+    HistoricalYear { year: 2022, home_price_change: 8.40, equity_return: -18.04, mortgage_rate: 5.34, rent_change: 8.40, inflation: 6.50 },
+    HistoricalYear { year: 2023, home_price_change: 6.50, equity_return: 26.06, mortgage_rate: 6.81, rent_change: 6.50, inflation: 3.40 },
+    HistoricalYear { year: 2024, home_price_change: 4.50, equity_return: 24.88, mortgage_rate: 6.72, rent_change: 4.40, inflation: 2.90 },
+];
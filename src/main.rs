@@ -1,5 +1,8 @@
 mod app;
+mod backtest;
 mod calculations;
+mod historical_data;
+mod monte_carlo;
 
 use app::App;
 use leptos::*;
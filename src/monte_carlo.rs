@@ -0,0 +1,213 @@
+use serde::Serialize;
+use crate::calculations::{calculate_with_path, Detail, Inputs, MarketPath};
+
+/// Most paths a run will sample; every path is a full simulation on the main thread
+pub const MAX_SIMULATIONS: usize = 5_000;
+
+/// Yearly rate distribution in percent
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateDistribution {
+    pub mean: f64,
+    pub volatility: f64, // Standard deviation of the yearly rate
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonteCarloSettings {
+    pub simulations: usize,
+    pub seed: u64,
+    pub home_appreciation: RateDistribution,
+    pub rent_increase: RateDistribution,
+    pub investment_return: RateDistribution,
+    pub home_stock_correlation: f64, // -1.0 to 1.0
+}
+
+impl MonteCarloSettings {
+    /// Centre the distributions on the deterministic assumptions, with
+    /// volatilities in line with long-run US history
+    pub fn from_inputs(inputs: &Inputs) -> Self {
+        Self {
+            simulations: 2_000,
+            seed: 42,
+            home_appreciation: RateDistribution { mean: inputs.home_appreciation, volatility: 5.0 },
+            rent_increase: RateDistribution { mean: inputs.rent_increase_rate, volatility: 2.0 },
+            investment_return: RateDistribution {
+                mean: MarketPath::neutral_investment_return(inputs),
+                volatility: 16.0,
+            },
+            home_stock_correlation: 0.2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PercentileBand {
+    pub p10: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p90: f64,
+}
+
+impl PercentileBand {
+    pub(crate) fn from_values(values: &mut [f64]) -> Self {
+        values.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            p10: percentile(values, 0.10),
+            p25: percentile(values, 0.25),
+            p50: percentile(values, 0.50),
+            p75: percentile(values, 0.75),
+            p90: percentile(values, 0.90),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MonteCarloResult {
+    pub simulations: usize,
+    pub buy_net_worth: Vec<PercentileBand>,  // One band per year
+    pub rent_net_worth: Vec<PercentileBand>, // One band per year
+    pub difference: PercentileBand,          // Final buy minus rent
+    pub buy_win_probability: f64,            // 0.0 to 1.0
+}
+
+/// Small seedable generator (SplitMix64) so runs are reproducible
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in (0, 1)
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal via Box-Muller
+    fn next_normal(&mut self) -> f64 {
+        let u1 = self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// A yearly rate can't lose more than everything
+fn sample_rate(distribution: &RateDistribution, z: f64) -> f64 {
+    (distribution.mean + distribution.volatility * z).max(-99.0)
+}
+
+fn sample_path(settings: &MonteCarloSettings, years: usize, rng: &mut Rng) -> MarketPath {
+    let rho = settings.home_stock_correlation.clamp(-1.0, 1.0);
+    let mut path = MarketPath { investment_anchor: settings.investment_return.mean, ..MarketPath::default() };
+
+    for _ in 0..years {
+        // Correlate home and stock shocks; rent growth moves independently
+        let z_stock = rng.next_normal();
+        let z_home = rho * z_stock + (1.0 - rho * rho).sqrt() * rng.next_normal();
+        let z_rent = rng.next_normal();
+
+        path.investment_return.push(sample_rate(&settings.investment_return, z_stock));
+        path.home_appreciation.push(sample_rate(&settings.home_appreciation, z_home));
+        path.rent_increase.push(sample_rate(&settings.rent_increase, z_rent));
+    }
+
+    path
+}
+
+/// Run `calculate` over many sampled market paths
+pub fn run_monte_carlo(inputs: &Inputs, settings: &MonteCarloSettings) -> MonteCarloResult {
+    let years = inputs.time_horizon_years as usize;
+    let simulations = settings.simulations.clamp(1, MAX_SIMULATIONS);
+    let mut rng = Rng::new(settings.seed);
+
+    // Net worth per year, per simulation
+    let mut buy_by_year = vec![Vec::with_capacity(simulations); years];
+    let mut rent_by_year = vec![Vec::with_capacity(simulations); years];
+    let mut differences = Vec::with_capacity(simulations);
+    let mut buy_wins = 0;
+
+    for _ in 0..simulations {
+        let path = sample_path(settings, years, &mut rng);
+        let result = calculate_with_path(inputs, &path, Detail::Summary);
+
+        for (year, snapshot) in result.yearly_snapshots.iter().enumerate() {
+            buy_by_year[year].push(snapshot.buy_net_worth);
+            rent_by_year[year].push(snapshot.rent_net_worth);
+        }
+        if result.difference > 0.0 {
+            buy_wins += 1;
+        }
+        differences.push(result.difference);
+    }
+
+    MonteCarloResult {
+        simulations,
+        buy_net_worth: buy_by_year.iter_mut().map(|v| PercentileBand::from_values(v)).collect(),
+        rent_net_worth: rent_by_year.iter_mut().map(|v| PercentileBand::from_values(v)).collect(),
+        difference: PercentileBand::from_values(&mut differences),
+        buy_win_probability: buy_wins as f64 / simulations as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::calculate;
+
+    fn settings(inputs: &Inputs) -> MonteCarloSettings {
+        MonteCarloSettings { simulations: 200, ..MonteCarloSettings::from_inputs(inputs) }
+    }
+
+    #[test]
+    fn same_seed_gives_same_result() {
+        let inputs = Inputs::default();
+        let first = run_monte_carlo(&inputs, &settings(&inputs));
+        assert_eq!(first, run_monte_carlo(&inputs, &settings(&inputs)));
+
+        let reseeded = MonteCarloSettings { seed: 7, ..settings(&inputs) };
+        assert_ne!(first, run_monte_carlo(&inputs, &reseeded));
+    }
+
+    #[test]
+    fn zero_volatility_matches_deterministic_run() {
+        let inputs = Inputs { buyer_investment_return: 7.0, renter_investment_return: 4.0, ..Inputs::default() };
+        let mut calm = settings(&inputs);
+        calm.home_appreciation.volatility = 0.0;
+        calm.rent_increase.volatility = 0.0;
+        calm.investment_return.volatility = 0.0;
+
+        let result = run_monte_carlo(&inputs, &calm);
+        let expected = calculate(&inputs);
+        let close = |a: f64, b: f64| (a - b).abs() < 0.01;
+        let band = &result.difference;
+        for p in [band.p10, band.p25, band.p50, band.p75, band.p90] {
+            assert!(close(p, expected.difference), "expected {}, got {p}", expected.difference);
+        }
+        for (band, snapshot) in result.buy_net_worth.iter().zip(&expected.yearly_snapshots) {
+            assert!(close(band.p10, snapshot.buy_net_worth) && close(band.p90, snapshot.buy_net_worth));
+        }
+        for (band, snapshot) in result.rent_net_worth.iter().zip(&expected.yearly_snapshots) {
+            assert!(close(band.p10, snapshot.rent_net_worth) && close(band.p90, snapshot.rent_net_worth));
+        }
+    }
+}